@@ -215,6 +215,18 @@ export default defineConfig({
 									label: "Rules sources",
 									link: "/linter/rules-sources",
 								},
+								{
+									label: "Migrate from ESLint",
+									link: "/linter/eslint-migration",
+								},
+								{
+									label: "Accessibility audit",
+									link: "/linter/accessibility-audit",
+								},
+								{
+									label: "Fixable rules",
+									link: "/linter/fixable-rules",
+								},
 							],
 						},
 					],
@@ -335,6 +347,10 @@ export default defineConfig({
 								"pt-BR": "Alterações",
 							},
 						},
+						{
+							label: "Removed rules",
+							link: "/internals/removed-rules",
+						},
 					],
 				},
 			],
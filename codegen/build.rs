@@ -0,0 +1,57 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+/// Exposes the version of `biome_analyze` resolved in the lockfile as
+/// `BIOME_ANALYZE_VERSION`, so that the generated pages can tell which
+/// analyzer they were generated from.
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    // The lockfile lives next to the manifest of the workspace
+    let lockfiles = [
+        manifest_dir.join("../Cargo.lock"),
+        manifest_dir.join("Cargo.lock"),
+    ];
+    for lockfile in &lockfiles {
+        println!("cargo:rerun-if-changed={}", lockfile.display());
+    }
+
+    let version = lockfiles
+        .iter()
+        .find_map(|lockfile| fs::read_to_string(lockfile).ok())
+        .and_then(|content| analyzer_version(&content))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=BIOME_ANALYZE_VERSION={version}");
+}
+
+/// Returns the version of `biome_analyze`, followed by the short hash of its
+/// commit when the crate comes from git. e.g. `0.5.7 (1a2b3c4)`
+fn analyzer_version(lockfile: &str) -> Option<String> {
+    let package = lockfile.split("[[package]]").find(|package| {
+        package
+            .lines()
+            .any(|line| line == "name = \"biome_analyze\"")
+    })?;
+
+    let field = |name: &str| {
+        package.lines().find_map(|line| {
+            line.strip_prefix(name)?
+                .strip_prefix(" = \"")?
+                .strip_suffix('"')
+                .map(str::to_string)
+        })
+    };
+
+    let version = field("version")?;
+    let commit = field("source").and_then(|source| {
+        source
+            .rsplit_once('#')
+            .map(|(_, commit)| commit.to_string())
+    });
+
+    Some(match commit {
+        Some(commit) => format!("{version} ({})", &commit[..commit.len().min(7)]),
+        None => version,
+    })
+}
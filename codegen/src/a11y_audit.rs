@@ -0,0 +1,116 @@
+use crate::extra_metadata::ExtraMetadata;
+use crate::lintdoc::{Warning, WarningKind};
+use anyhow::Result;
+use biome_analyze::RuleMetadata;
+use biome_string_case::Case;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// Generates the page that maps the rules of the `a11y` group to the WCAG
+/// success criteria and the ARIA practices they enforce.
+///
+/// The criteria are maintained in `codegen/extra-metadata.json`. The rules
+/// without criteria are reported in `warnings`.
+pub(crate) fn generate_a11y_audit(
+    rules: &BTreeMap<&'static str, RuleMetadata>,
+    extra_metadata: &ExtraMetadata,
+    warnings: &mut Vec<(&'static str, Warning)>,
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+
+    writeln!(
+        buffer,
+        r#"---
+title: Accessibility audit
+description: A page that maps the accessibility rules to the WCAG success criteria and the ARIA practices they enforce
+---
+"#
+    )?;
+
+    writeln!(
+        buffer,
+        "The following table lists the rules of the `a11y` group, with the WCAG success criteria and the ARIA practices they enforce."
+    )?;
+    writeln!(buffer)?;
+    writeln!(buffer, "| Biome rule | Criteria |")?;
+    writeln!(buffer, "| ---- | ---- |")?;
+    for rule in rules.keys() {
+        let criteria = extra_metadata
+            .rule(rule)
+            .map_or(&[][..], |extra| extra.accessibility_criteria.as_slice());
+        if criteria.is_empty() {
+            warnings.push((
+                rule,
+                Warning::new(
+                    WarningKind::MissingAccessibilityCriteria,
+                    "the accessibility rule doesn't have `accessibilityCriteria` in `codegen/extra-metadata.json`",
+                ),
+            ));
+        }
+
+        let criteria = criteria
+            .iter()
+            .map(|criterion| match &criterion.url {
+                Some(url) => format!("[{}]({url})", criterion.name),
+                None => criterion.name.clone(),
+            })
+            .collect::<Vec<_>>();
+        writeln!(
+            buffer,
+            "| [{rule}](/linter/rules/{}) | {} |",
+            Case::Kebab.convert(rule),
+            criteria.join("<br/>")
+        )?;
+    }
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_are_mapped_to_their_criteria() {
+        let rule = |name| RuleMetadata::new("1.0.0", name, "").language("jsx");
+        let rules = BTreeMap::from([
+            ("useAltText", rule("useAltText")),
+            ("useButtonType", rule("useButtonType")),
+        ]);
+        let extra_metadata: ExtraMetadata = serde_json::from_str(
+            r#"{ "rules": { "useAltText": { "accessibilityCriteria": [
+                { "name": "WCAG 1.1.1 Non-text Content", "url": "https://www.w3.org/WAI/WCAG22/Understanding/non-text-content" },
+                { "name": "ARIA img role" }
+            ] } } }"#,
+        )
+        .unwrap();
+
+        let mut warnings = Vec::new();
+        let content =
+            String::from_utf8(generate_a11y_audit(&rules, &extra_metadata, &mut warnings).unwrap())
+                .unwrap();
+        assert!(content.contains(
+            "| [useAltText](/linter/rules/use-alt-text) | [WCAG 1.1.1 Non-text Content](https://www.w3.org/WAI/WCAG22/Understanding/non-text-content)<br/>ARIA img role |\n"
+        ));
+        assert!(content.contains("| [useButtonType](/linter/rules/use-button-type) |  |\n"));
+        assert_eq!(
+            warnings.iter().map(|(rule, _)| *rule).collect::<Vec<_>>(),
+            ["useButtonType"]
+        );
+    }
+
+    #[test]
+    fn shipped_rules_have_criteria() {
+        let extra_metadata = ExtraMetadata::load().unwrap();
+        for rule in ["noAccessKey", "useAltText", "useValidLang"] {
+            let criteria = &extra_metadata.rule(rule).unwrap().accessibility_criteria;
+            assert!(
+                criteria
+                    .iter()
+                    .all(|criterion| criterion.name.starts_with("WCAG ") && criterion.url.is_some()),
+                "{rule}: {criteria:?}"
+            );
+            assert!(!criteria.is_empty(), "{rule}");
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::lintdoc::{FileEdits, RulePage};
+use crate::project_root;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Computes the keys of the cached pages and examples from the inputs they
+/// were generated from.
+///
+/// The cache outlives the toolchain that wrote it, so the key doesn't use
+/// [std::hash::DefaultHasher], whose output can change between releases of
+/// Rust. The bytes of the inputs are hashed with 64-bit FNV-1a, and each input
+/// is prefixed with its length so that two lists of inputs can't write the
+/// same bytes.
+#[derive(Debug)]
+pub(crate) struct CacheKey(u64);
+
+impl CacheKey {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    pub(crate) fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+    }
+
+    pub(crate) fn u64(&mut self, value: u64) -> &mut Self {
+        self.write(&value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn bool(&mut self, value: bool) -> &mut Self {
+        self.write(&[u8::from(value)]);
+        self
+    }
+
+    pub(crate) fn str(&mut self, value: &str) -> &mut Self {
+        self.u64(value.len() as u64);
+        self.write(value.as_bytes());
+        self
+    }
+
+    /// Writes a string that may be missing, `None` and `Some("")` give
+    /// different keys
+    pub(crate) fn option(&mut self, value: Option<&str>) -> &mut Self {
+        self.bool(value.is_some());
+        if let Some(value) = value {
+            self.str(value);
+        }
+        self
+    }
+
+    pub(crate) fn list(&mut self, values: &[&str]) -> &mut Self {
+        self.u64(values.len() as u64);
+        for value in values {
+            self.str(value);
+        }
+        self
+    }
+
+    /// Writes the value serialized as JSON
+    pub(crate) fn json(&mut self, value: &impl Serialize) -> &mut Self {
+        let json =
+            serde_json::to_string(value).expect("the inputs of a cache key are serializable");
+        self.str(&json)
+    }
+
+    pub(crate) fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+/// The cache of the generated rule pages, used by incremental builds to skip
+/// the analysis of the rules whose inputs didn't change.
+///
+/// The cache is stored in the directory given by `--cache-dir`, see
+/// [cache_file_path] for its default.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct RulePagesCache {
+    entries: BTreeMap<String, CachedRulePage>,
+    /// The analyzed examples of each rule, reused when the page of the rule
+    /// is generated again
+    #[serde(default)]
+    snippets: BTreeMap<String, BTreeMap<u64, CachedSnippet>>,
+}
+
+/// The outcome of the analysis of an example
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct CachedSnippet {
+    /// The rendered diagnostics
+    pub(crate) diagnostics: Vec<u8>,
+    pub(crate) messages: Vec<String>,
+    pub(crate) fixed_code: Option<String>,
+    #[serde(default)]
+    pub(crate) edits: FileEdits,
+    #[serde(default)]
+    pub(crate) replacements: Vec<(String, String)>,
+}
+
+/// The analyzed examples of a rule, keyed by the hash of the example and of
+/// everything its analysis depends on, including the version of the analyzer.
+///
+/// Only the examples looked up or inserted while generating the page are
+/// kept, so that the examples removed from the documentation are dropped.
+#[derive(Debug, Default)]
+pub(crate) struct SnippetsCache {
+    previous: BTreeMap<u64, CachedSnippet>,
+    current: BTreeMap<u64, CachedSnippet>,
+}
+
+impl SnippetsCache {
+    pub(crate) fn get(&mut self, key: u64) -> Option<CachedSnippet> {
+        let snippet = self.previous.remove(&key)?;
+        self.current.insert(key, snippet.clone());
+        Some(snippet)
+    }
+
+    pub(crate) fn insert(&mut self, key: u64, snippet: CachedSnippet) {
+        self.current.insert(key, snippet);
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedRulePage {
+    /// The hash of the inputs the page was generated from
+    key: u64,
+    page: RulePage,
+}
+
+impl RulePagesCache {
+    /// Loads the cache from disk. A missing or corrupted cache is replaced by
+    /// an empty one, so that every page is generated again.
+    pub(crate) fn load(path: &Path) -> Self {
+        let Ok(content) = fs::read_to_string(path) else {
+            return Self::default();
+        };
+
+        match serde_json::from_str(&content) {
+            Ok(cache) => cache,
+            Err(err) => {
+                eprintln!(
+                    "The cache {} is corrupted, all the pages will be generated again: {err}",
+                    path.display()
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Returns the page of the rule, if it was generated from the same inputs
+    pub(crate) fn get(&self, rule: &str, key: u64) -> Option<&RulePage> {
+        self.entries
+            .get(rule)
+            .filter(|entry| entry.key == key)
+            .map(|entry| &entry.page)
+    }
+
+    pub(crate) fn insert(&mut self, rule: &str, key: u64, page: RulePage) {
+        self.entries
+            .insert(rule.to_string(), CachedRulePage { key, page });
+    }
+
+    /// Takes the analyzed examples of the rule, they're given back with
+    /// [RulePagesCache::insert_snippets] once the page is generated
+    pub(crate) fn take_snippets(&mut self, rule: &str) -> SnippetsCache {
+        SnippetsCache {
+            previous: self.snippets.remove(rule).unwrap_or_default(),
+            current: BTreeMap::new(),
+        }
+    }
+
+    pub(crate) fn insert_snippets(&mut self, rule: &str, snippets: SnippetsCache) {
+        self.snippets.insert(rule.to_string(), snippets.current);
+    }
+
+    pub(crate) fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string(self)?)?;
+
+        Ok(())
+    }
+}
+
+/// Returns the path of the cache file in the directory, which defaults to
+/// `codegen-cache` inside `CARGO_TARGET_DIR`, or inside the `target`
+/// directory of the project
+pub(crate) fn cache_file_path(directory: Option<&Path>) -> PathBuf {
+    let directory = directory
+        .map(Path::to_path_buf)
+        .or_else(|| {
+            env::var_os("CARGO_TARGET_DIR")
+                .map(|target| PathBuf::from(target).join("codegen-cache"))
+        })
+        .unwrap_or_else(|| project_root().join("target/codegen-cache"));
+
+    directory.join("rule-pages.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(content: &str) -> RulePage {
+        serde_json::from_value(serde_json::json!({
+            "content": content,
+            "summary": "",
+            "ignore_reasons": [],
+        }))
+        .unwrap()
+    }
+
+    fn snippet(message: &str) -> CachedSnippet {
+        CachedSnippet {
+            diagnostics: Vec::new(),
+            messages: vec![message.to_string()],
+            fixed_code: None,
+            edits: FileEdits::new(),
+            replacements: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn key_is_stable() {
+        // FNV-1a of the empty input
+        assert_eq!(CacheKey::new().finish(), 0xcbf2_9ce4_8422_2325);
+        // The keys written by a previous toolchain are still found
+        assert_eq!(
+            CacheKey::new().str("noDebugger").bool(true).finish(),
+            0xbb2f_af88_6966_28fe
+        );
+
+        // The inputs are delimited
+        assert_ne!(
+            CacheKey::new().str("ab").str("c").finish(),
+            CacheKey::new().str("a").str("bc").finish()
+        );
+        assert_ne!(
+            CacheKey::new().option(None).finish(),
+            CacheKey::new().option(Some("")).finish()
+        );
+        assert_ne!(
+            CacheKey::new().list(&["a", "b"]).finish(),
+            CacheKey::new().list(&["a"]).str("b").finish()
+        );
+    }
+
+    #[test]
+    fn page_is_reused_for_the_same_key() {
+        let mut cache = RulePagesCache::default();
+        cache.insert("noDebugger", 1, page("debugger"));
+
+        assert!(cache.get("noDebugger", 1).is_some());
+        assert!(cache.get("noDebugger", 2).is_none());
+        assert!(cache.get("noConsole", 1).is_none());
+    }
+
+    #[test]
+    fn snippets_of_removed_examples_are_dropped() {
+        let mut cache = RulePagesCache::default();
+        let mut snippets = cache.take_snippets("noDebugger");
+        snippets.insert(1, snippet("first"));
+        snippets.insert(2, snippet("second"));
+        cache.insert_snippets("noDebugger", snippets);
+
+        // Only the first example is still in the documentation
+        let mut snippets = cache.take_snippets("noDebugger");
+        assert_eq!(snippets.get(1).unwrap().messages, ["first"]);
+        cache.insert_snippets("noDebugger", snippets);
+
+        let mut snippets = cache.take_snippets("noDebugger");
+        assert!(snippets.get(2).is_none());
+        assert!(snippets.get(1).is_some());
+    }
+
+    #[test]
+    fn cache_without_snippets_is_loaded() {
+        let mut cache = RulePagesCache::default();
+        cache.insert("noDebugger", 1, page("debugger"));
+        let mut content = serde_json::to_value(&cache).unwrap();
+        content.as_object_mut().unwrap().remove("snippets");
+
+        let cache: RulePagesCache = serde_json::from_value(content).unwrap();
+        assert!(cache.get("noDebugger", 1).is_some());
+        assert!(cache.snippets.is_empty());
+    }
+
+    #[test]
+    fn corrupted_cache_is_loaded_empty() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = cache_file_path(Some(directory.path()));
+        fs::write(&path, "{ not json").unwrap();
+        assert!(RulePagesCache::load(&path).entries.is_empty());
+
+        let mut cache = RulePagesCache::default();
+        cache.insert("noDebugger", 1, page("debugger"));
+        cache.save(&path).unwrap();
+        assert!(RulePagesCache::load(&path).get("noDebugger", 1).is_some());
+
+        let missing = cache_file_path(Some(&directory.path().join("missing")));
+        assert!(RulePagesCache::load(&missing).entries.is_empty());
+    }
+}
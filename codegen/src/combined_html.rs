@@ -0,0 +1,266 @@
+use crate::lintdoc::heading_anchor;
+use anyhow::Result;
+use biome_string_case::Case;
+use pulldown_cmark::{html::write_html, CowStr, Event, Parser, Tag, TagEnd};
+use std::io::Write;
+
+/// Concatenates the rule pages into a single HTML document that doesn't
+/// depend on the site, so that it can be printed or exported to PDF.
+///
+/// The pages are given in the order of the index: grouped, and alphabetical
+/// inside each group. Each rule is a section whose anchor is the name of its
+/// page, e.g. `#no-debugger`, and the anchors of its headings, of its
+/// examples and of its diagnostic category are prefixed with it, like the
+/// links to them.
+///
+/// The asides and the icons of the pages are written for Starlight, they're
+/// converted to plain HTML.
+pub(crate) fn generate_combined_html(
+    pages: &[(&'static str, &'static str, String)],
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+
+    writeln!(
+        buffer,
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>Biome lint rules</title>
+</head>
+<body>
+<h1>Biome lint rules</h1>"#
+    )?;
+
+    let mut current_group = None;
+    for &(group, rule, ref content) in pages {
+        if current_group != Some(group) {
+            if current_group.is_some() {
+                writeln!(buffer, "</section>")?;
+            }
+            writeln!(buffer, "<section id=\"{group}\">")?;
+            writeln!(buffer, "<h2>{group}</h2>")?;
+            current_group = Some(group);
+        }
+
+        let dashed_rule = Case::Kebab.convert(rule);
+        writeln!(buffer, "<article id=\"{dashed_rule}\">")?;
+        writeln!(buffer, "<h3>{rule}</h3>")?;
+        let body = page_body(content);
+        let mut html = Vec::new();
+        write_html(
+            &mut html,
+            with_heading_ids(Parser::new(&body), &dashed_rule),
+        )?;
+        let html = String::from_utf8(html)?
+            .replace("id=\"example-", &format!("id=\"{dashed_rule}-example-"))
+            .replace("id=\"lint-", &format!("id=\"{dashed_rule}-lint-"))
+            .replace("href=\"#", &format!("href=\"#{dashed_rule}-"));
+        buffer.extend(html.as_bytes());
+        writeln!(buffer, "</article>")?;
+    }
+    if current_group.is_some() {
+        writeln!(buffer, "</section>")?;
+    }
+
+    writeln!(buffer, "</body>\n</html>")?;
+
+    Ok(buffer)
+}
+
+/// Returns the Markdown of a rule page, without its frontmatter and the
+/// imports of the components, with its asides and its icons in HTML
+fn page_body(content: &str) -> String {
+    let body = content
+        .strip_prefix("---\n")
+        .and_then(|rest| rest.split_once("\n---\n"))
+        .map_or(content, |(_, body)| body);
+
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+    for line in body.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if in_code_block {
+            lines.push(line.to_string());
+        } else if line.starts_with("import ") {
+            continue;
+        } else if line == ":::" {
+            // The blank lines end the HTML blocks, so that the content of
+            // the aside is parsed as Markdown
+            lines.extend([String::new(), "</aside>".to_string(), String::new()]);
+        } else if let Some(aside) = line.strip_prefix(":::") {
+            let (aside, title) = match aside.split_once('[') {
+                Some((aside, title)) => (aside, title.strip_suffix(']')),
+                None => (aside, None),
+            };
+            lines.push(format!("<aside class=\"{aside}\">"));
+            if let Some(title) = title {
+                lines.push(format!("<p><strong>{title}</strong></p>"));
+            }
+            lines.push(String::new());
+        } else {
+            lines.push(replace_icons(line));
+        }
+    }
+    lines.join("\n")
+}
+
+/// Replaces the `Icon` components of the line with their label
+fn replace_icons(line: &str) -> String {
+    const ICON: &str = "<Icon ";
+
+    let mut result = String::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(ICON) {
+        let Some(end) = rest[start..].find("/>") else {
+            break;
+        };
+        let component = &rest[start..start + end];
+        let label = component
+            .split_once("label=\"")
+            .and_then(|(_, label)| label.split_once('"'))
+            .map_or("", |(label, _)| label);
+        result.push_str(&rest[..start]);
+        result.push_str(label);
+        rest = &rest[start + end + "/>".len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Gives the headings the anchors that Starlight computes for them, prefixed
+/// with the rule like the links to them
+fn with_heading_ids<'a>(
+    events: impl Iterator<Item = Event<'a>>,
+    dashed_rule: &str,
+) -> impl Iterator<Item = Event<'a>> {
+    let mut events = events.collect::<Vec<_>>();
+    let mut anchors = Vec::new();
+    for index in 0..events.len() {
+        if let Event::Start(Tag::Heading { id: None, .. }) = &events[index] {
+            let text = events[index + 1..]
+                .iter()
+                .take_while(|event| !matches!(event, Event::End(TagEnd::Heading(_))))
+                .filter_map(|event| match event {
+                    Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                    _ => None,
+                })
+                .collect::<String>();
+            let anchor = heading_anchor(&text, &anchors);
+            if let Event::Start(Tag::Heading { id, .. }) = &mut events[index] {
+                *id = Some(CowStr::from(format!("{dashed_rule}-{anchor}")));
+            }
+            anchors.push(anchor);
+        }
+    }
+    events.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_are_sections_with_anchors() {
+        let page = |title: &str| {
+            format!("---\ntitle: {title}\n---\nimport {{ Icon }} from \"@astrojs/starlight/components\";\n\n## Examples\n\n<a id=\"example-1\"></a>\n\n[the example](#example-1)\n")
+        };
+        let pages = [
+            (
+                "correctness",
+                "noUnusedVariables",
+                page("noUnusedVariables"),
+            ),
+            ("suspicious", "noConsole", page("noConsole")),
+            ("suspicious", "noDebugger", page("noDebugger")),
+        ];
+        let html = String::from_utf8(generate_combined_html(&pages).unwrap()).unwrap();
+
+        assert_eq!(html.matches("<section id=").count(), 2, "{html}");
+        assert!(html.contains("<section id=\"suspicious\">\n<h2>suspicious</h2>"));
+        for (rule, dashed_rule) in [
+            ("noUnusedVariables", "no-unused-variables"),
+            ("noConsole", "no-console"),
+            ("noDebugger", "no-debugger"),
+        ] {
+            assert!(
+                html.contains(&format!("<article id=\"{dashed_rule}\">\n<h3>{rule}</h3>")),
+                "{html}"
+            );
+            assert!(
+                html.contains(&format!("id=\"{dashed_rule}-example-1\"")),
+                "{html}"
+            );
+            assert!(
+                html.contains(&format!("href=\"#{dashed_rule}-example-1\"")),
+                "{html}"
+            );
+        }
+        assert!(!html.contains("import "), "{html}");
+        assert!(!html.contains("title: "), "{html}");
+    }
+
+    #[test]
+    fn asides_icons_and_headings_are_html() {
+        let pages = [(
+            "suspicious",
+            "noDebugger",
+            "---\ntitle: noDebugger\n---\n:::note\n- This rule is recommended.\n:::\n\n:::caution[Unreleased]\nNot released yet.\n:::\n\n## How to configure\n\n## How to configure\n\nSee [the options](#how-to-configure). <span class='inline-icon'><Icon name=\"warning\" label=\"The rule has an unsafe fix\" size=\"1.2rem\" /></span>\n\n```js\n:::\n```\n".to_string(),
+        )];
+        let html = String::from_utf8(generate_combined_html(&pages).unwrap()).unwrap();
+
+        assert!(
+            html.contains(
+                "<aside class=\"note\">\n<ul>\n<li>This rule is recommended.</li>\n</ul>\n</aside>"
+            ),
+            "{html}"
+        );
+        assert!(
+            html.contains("<aside class=\"caution\">\n<p><strong>Unreleased</strong></p>\n<p>Not released yet.</p>\n</aside>"),
+            "{html}"
+        );
+        assert!(!html.contains("<Icon"), "{html}");
+        assert!(
+            html.contains("<span class='inline-icon'>The rule has an unsafe fix</span>"),
+            "{html}"
+        );
+        assert!(
+            html.contains("<h2 id=\"no-debugger-how-to-configure\">"),
+            "{html}"
+        );
+        assert!(
+            html.contains("<h2 id=\"no-debugger-how-to-configure-1\">"),
+            "{html}"
+        );
+        assert!(
+            html.contains("href=\"#no-debugger-how-to-configure\""),
+            "{html}"
+        );
+        // The code blocks are kept as they are
+        assert!(
+            html.contains("<code class=\"language-js\">:::\n</code>"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn category_anchors_are_prefixed_with_the_rule() {
+        let pages = [(
+            "suspicious",
+            "noDebugger",
+            "---\ntitle: noDebugger\n---\n<p id=\"lint-suspicious-no-debugger\" data-category=\"lint/suspicious/noDebugger\"><strong>Diagnostic Category: <a href=\"#lint-suspicious-no-debugger\"><code>lint/suspicious/noDebugger</code></a></strong></p>\n".to_string(),
+        )];
+        let html = String::from_utf8(generate_combined_html(&pages).unwrap()).unwrap();
+
+        assert!(
+            html.contains("id=\"no-debugger-lint-suspicious-no-debugger\""),
+            "{html}"
+        );
+        assert!(
+            html.contains("href=\"#no-debugger-lint-suspicious-no-debugger\""),
+            "{html}"
+        );
+    }
+}
@@ -0,0 +1,134 @@
+use crate::extra_metadata::ExtraMetadata;
+use anyhow::Result;
+use biome_analyze::{RuleMetadata, RuleSource, RuleSourceKind};
+use biome_string_case::Case;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// Generates the page that maps the rules of ESLint and of its plugins to
+/// the Biome rules that implement them
+pub(crate) fn generate_eslint_migration(
+    rules: BTreeMap<&str, BTreeMap<&'static str, RuleMetadata>>,
+    extra_metadata: &ExtraMetadata,
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+
+    writeln!(
+        buffer,
+        r#"---
+title: Migrate from ESLint
+description: A page that maps the ESLint rules to their Biome equivalent
+---
+"#
+    )?;
+
+    // The ESLint rules, keyed by the name used in an ESLint configuration,
+    // and the Biome rules that implement them
+    let mut eslint_rules = BTreeMap::<String, Vec<(&str, &str)>>::new();
+    for rule in rules.into_values().flat_map(BTreeMap::into_values) {
+        for source in rule.sources {
+            if !is_eslint_source(source) {
+                continue;
+            }
+            let relationship = match extra_metadata.source_kind(&rule, source) {
+                RuleSourceKind::SameLogic => "same",
+                RuleSourceKind::Inspired => "inspired",
+            };
+            eslint_rules
+                .entry(source.to_namespaced_rule_name())
+                .or_default()
+                .push((rule.name, relationship));
+        }
+    }
+
+    writeln!(
+        buffer,
+        "The following table lists the ESLint rules, including the ones of the ESLint plugins, that have a Biome equivalent. \
+A **same** rule implements the logic of the ESLint rule, while an **inspired** rule may behave differently."
+    )?;
+    writeln!(buffer)?;
+    writeln!(buffer, "| ESLint rule | Biome rule | Behavior |")?;
+    writeln!(buffer, "| ---- | ---- | ---- |")?;
+    for (eslint_rule, biome_rules) in eslint_rules {
+        for (biome_rule, relationship) in biome_rules {
+            writeln!(
+                buffer,
+                "| `{eslint_rule}` | [{biome_rule}](/linter/rules/{}) | {relationship} |",
+                Case::Kebab.convert(biome_rule)
+            )?;
+        }
+    }
+
+    Ok(buffer)
+}
+
+/// Whether the source is a rule of ESLint or of one of its plugins
+fn is_eslint_source(source: &RuleSource) -> bool {
+    matches!(
+        source,
+        RuleSource::Eslint(_)
+            | RuleSource::EslintBarrelFiles(_)
+            | RuleSource::EslintImport(_)
+            | RuleSource::EslintImportAccess(_)
+            | RuleSource::EslintJest(_)
+            | RuleSource::EslintJsxA11y(_)
+            | RuleSource::EslintMysticatea(_)
+            | RuleSource::EslintN(_)
+            | RuleSource::EslintNext(_)
+            | RuleSource::EslintReact(_)
+            | RuleSource::EslintReactHooks(_)
+            | RuleSource::EslintSolid(_)
+            | RuleSource::EslintSonarJs(_)
+            | RuleSource::EslintStylistic(_)
+            | RuleSource::EslintTypeScript(_)
+            | RuleSource::EslintUnicorn(_)
+            | RuleSource::EslintUnusedImports(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eslint_rules_are_mapped_to_biome_rules() {
+        let no_debugger = RuleMetadata::new("1.0.0", "noDebugger", "Disallow `debugger`.")
+            .sources(&[
+                RuleSource::Eslint("no-debugger"),
+                RuleSource::Clippy("dbg_macro"),
+            ])
+            .source_kind(RuleSourceKind::SameLogic);
+        let no_redeclare = RuleMetadata::new("1.0.0", "noRedeclare", "Disallow redeclarations.")
+            .sources(&[
+                RuleSource::Eslint("no-redeclare"),
+                RuleSource::EslintTypeScript("no-redeclare"),
+            ])
+            .source_kind(RuleSourceKind::Inspired);
+        let rules = BTreeMap::from([(
+            "suspicious",
+            BTreeMap::from([("noDebugger", no_debugger), ("noRedeclare", no_redeclare)]),
+        )]);
+
+        // The TypeScript source of `noRedeclare` has its own kind
+        let extra_metadata: ExtraMetadata = serde_json::from_str(
+            r#"{ "rules": { "noRedeclare": { "sourceKinds": { "@typescript-eslint/no-redeclare": "same" } } } }"#,
+        )
+        .unwrap();
+
+        let buffer = generate_eslint_migration(rules, &extra_metadata).unwrap();
+        let rows = String::from_utf8(buffer)
+            .unwrap()
+            .lines()
+            .filter(|line| line.starts_with("| `"))
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                "| `@typescript-eslint/no-redeclare` | [noRedeclare](/linter/rules/no-redeclare) | same |",
+                "| `no-debugger` | [noDebugger](/linter/rules/no-debugger) | same |",
+                "| `no-redeclare` | [noRedeclare](/linter/rules/no-redeclare) | inspired |",
+            ]
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use crate::project_root;
+use anyhow::{Context, Result};
+use biome_analyze::options::JsxRuntime;
+use biome_analyze::{RuleMetadata, RuleSource, RuleSourceKind};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::str::FromStr;
+
+/// The metadata of the rules that is specific to the website, and so can't
+/// be part of the `RuleMetadata` of the analyzer.
+///
+/// It's maintained in `codegen/extra-metadata.json`.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct ExtraMetadata {
+    /// The extra metadata of each rule, keyed by the name of the rule
+    #[serde(default)]
+    rules: BTreeMap<String, RuleExtraMetadata>,
+    /// The icons allowed by `--validate-icons`, besides the ones used by the
+    /// generator
+    #[serde(default)]
+    known_icons: Vec<String>,
+    /// The prefixes the names of the rules are expected to start with,
+    /// [DEFAULT_RULE_NAME_PREFIXES] when it's empty
+    #[serde(default)]
+    rule_name_prefixes: Vec<String>,
+    /// The rules removed from Biome, which aren't in the registries anymore
+    #[serde(default)]
+    pub(crate) removed_rules: Vec<RemovedRule>,
+}
+
+/// The prefixes of the names of the rules: `no` for the rules that forbid a
+/// pattern and `use` for the ones that enforce one
+const DEFAULT_RULE_NAME_PREFIXES: &[&str] = &["no", "use"];
+
+/// A rule that was removed from Biome, or renamed
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct RemovedRule {
+    pub(crate) name: String,
+    /// The version that removed the rule
+    pub(crate) version: String,
+    /// The rule that replaces it, when it was renamed
+    #[serde(default)]
+    pub(crate) replaced_by: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct RuleExtraMetadata {
+    /// Entries merged into the frontmatter of the page of the rule
+    #[serde(default)]
+    pub(crate) frontmatter: Map<String, Value>,
+    /// The frameworks the rule applies to, the rule applies to any code
+    /// when it's empty
+    #[serde(default)]
+    pub(crate) frameworks: Vec<Framework>,
+    /// The changes of the behavior of the rule across versions
+    #[serde(default)]
+    pub(crate) changes: Vec<RuleChange>,
+    /// The WCAG success criteria and the ARIA practices enforced by an
+    /// accessibility rule
+    #[serde(default)]
+    pub(crate) accessibility_criteria: Vec<AccessibilityCriterion>,
+    /// The runtime cost of the pattern reported by a rule of the
+    /// `performance` group, in Markdown
+    #[serde(default)]
+    pub(crate) performance_impact: Option<String>,
+    /// Why the fix of a rule with an unsafe fix can change the behavior of
+    /// the code, in Markdown
+    #[serde(default)]
+    pub(crate) unsafe_fix_rationale: Option<String>,
+    /// Whether the code reported by the rule is already rewritten by the
+    /// formatter of Biome, which makes the rule unnecessary
+    #[serde(default)]
+    pub(crate) formatter_overlap: bool,
+    /// How the rule relates to each of its sources, keyed by the name of the
+    /// source in the configuration of its tool, like `no-debugger` for ESLint
+    /// or `clippy/dbg_macro`. It overrides the `source_kind` of the rule,
+    /// which applies to every source.
+    #[serde(default)]
+    pub(crate) source_kinds: BTreeMap<String, SourceKind>,
+    /// The rules this rule is intended to replace, for example a nursery
+    /// rule that replaces a stable one. The replaced rules link back to it.
+    #[serde(default)]
+    pub(crate) supersedes: Vec<String>,
+}
+
+/// How a rule relates to one of its sources
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum SourceKind {
+    /// The rule implements the logic of the source
+    Same,
+    /// The rule may behave differently than the source
+    Inspired,
+}
+
+/// Returns how the rule relates to one of its sources, see
+/// [ExtraMetadata::source_kind]
+pub(crate) fn rule_source_kind(
+    meta: &RuleMetadata,
+    extra: Option<&RuleExtraMetadata>,
+    source: &RuleSource,
+) -> RuleSourceKind {
+    extra
+        .and_then(|extra| extra.source_kinds.get(&source.to_namespaced_rule_name()))
+        .map_or_else(|| meta.source_kind.unwrap_or_default(), |&kind| kind.into())
+}
+
+impl From<SourceKind> for RuleSourceKind {
+    fn from(kind: SourceKind) -> Self {
+        match kind {
+            SourceKind::Same => Self::SameLogic,
+            SourceKind::Inspired => Self::Inspired,
+        }
+    }
+}
+
+/// A change of the behavior of a rule
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct RuleChange {
+    /// The version that introduced the change, for example `1.8.0`
+    pub(crate) version: String,
+    /// What changed, in Markdown
+    pub(crate) note: String,
+}
+
+/// A WCAG success criterion or an ARIA practice
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub(crate) struct AccessibilityCriterion {
+    /// For example `WCAG 1.1.1 Non-text Content`
+    pub(crate) name: String,
+    /// The page that describes the criterion
+    #[serde(default)]
+    pub(crate) url: Option<String>,
+}
+
+/// A framework that rules can be specific to
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Framework {
+    /// React with the automatic JSX runtime
+    React,
+    /// React with the classic JSX runtime, which requires `React` to be in scope
+    ReactClassic,
+    Preact,
+    Solid,
+    Qwik,
+    Vue,
+    Svelte,
+    Astro,
+}
+
+impl Framework {
+    /// The JSX runtime the examples of the framework are analyzed with
+    pub(crate) fn jsx_runtime(self) -> JsxRuntime {
+        match self {
+            Self::ReactClassic => JsxRuntime::ReactClassic,
+            _ => JsxRuntime::Transparent,
+        }
+    }
+}
+
+impl FromStr for Framework {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self> {
+        serde_json::from_value(Value::String(input.to_string()))
+            .with_context(|| format!("unknown framework `{input}`"))
+    }
+}
+
+impl fmt::Display for Framework {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::React => "React",
+            Self::ReactClassic => "React (classic JSX runtime)",
+            Self::Preact => "Preact",
+            Self::Solid => "Solid",
+            Self::Qwik => "Qwik",
+            Self::Vue => "Vue",
+            Self::Svelte => "Svelte",
+            Self::Astro => "Astro",
+        };
+        f.write_str(name)
+    }
+}
+
+impl ExtraMetadata {
+    pub(crate) fn load() -> Result<Self> {
+        let path = project_root().join("codegen/extra-metadata.json");
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path)?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub(crate) fn rule(&self, rule: &str) -> Option<&RuleExtraMetadata> {
+        self.rules.get(rule)
+    }
+
+    /// Returns how the rule relates to one of its sources: the kind set in
+    /// `sourceKinds`, or the `source_kind` of the rule
+    pub(crate) fn source_kind(&self, meta: &RuleMetadata, source: &RuleSource) -> RuleSourceKind {
+        rule_source_kind(meta, self.rule(meta.name), source)
+    }
+
+    /// Returns the rules that supersede the rule, the ones that list it in
+    /// their `supersedes`
+    pub(crate) fn superseded_by(&self, rule: &str) -> Vec<&str> {
+        self.rules
+            .iter()
+            .filter(|(_, extra)| extra.supersedes.iter().any(|superseded| superseded == rule))
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Returns the pairs of a rule and of a rule it supersedes
+    pub(crate) fn supersessions(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.rules.iter().flat_map(|(name, extra)| {
+            extra
+                .supersedes
+                .iter()
+                .map(move |superseded| (name.as_str(), superseded.as_str()))
+        })
+    }
+
+    pub(crate) fn is_known_icon(&self, icon: &str) -> bool {
+        self.known_icons.iter().any(|known_icon| known_icon == icon)
+    }
+
+    /// Whether the name of the rule starts with one of the expected prefixes,
+    /// followed by an uppercase letter, for example `noDebugger`
+    pub(crate) fn has_conventional_name(&self, rule: &str) -> bool {
+        let has_prefix = |prefix: &str| {
+            rule.strip_prefix(prefix)
+                .and_then(|rest| rest.chars().next())
+                .is_some_and(|char| char.is_ascii_uppercase())
+        };
+        if self.rule_name_prefixes.is_empty() {
+            DEFAULT_RULE_NAME_PREFIXES
+                .iter()
+                .any(|prefix| has_prefix(prefix))
+        } else {
+            self.rule_name_prefixes
+                .iter()
+                .any(|prefix| has_prefix(prefix))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<ExtraMetadata> {
+        Ok(serde_json::from_str(content)?)
+    }
+
+    #[test]
+    fn checked_in_metadata_is_valid() {
+        ExtraMetadata::load().unwrap();
+    }
+
+    #[test]
+    fn checked_in_metadata_describes_the_performance_rules() {
+        let extra_metadata = ExtraMetadata::load().unwrap();
+        for rule in [
+            "noAccumulatingSpread",
+            "noBarrelFile",
+            "noDelete",
+            "noReExportAll",
+        ] {
+            let impact = extra_metadata
+                .rule(rule)
+                .and_then(|extra| extra.performance_impact.as_deref());
+            assert!(impact.is_some_and(|impact| !impact.is_empty()), "{rule}");
+        }
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        assert!(parse(r#"{ "rules": { "noDebugger": { "frontmater": {} } } }"#).is_err());
+        assert!(parse(r#"{ "rule": {} }"#).is_err());
+    }
+
+    #[test]
+    fn frameworks_are_parsed() {
+        let extra_metadata =
+            parse(r#"{ "rules": { "useHookAtTopLevel": { "frameworks": ["react", "preact"] } } }"#)
+                .unwrap();
+        assert_eq!(
+            extra_metadata.rule("useHookAtTopLevel").unwrap().frameworks,
+            [Framework::React, Framework::Preact]
+        );
+        assert!(extra_metadata.rule("noDebugger").is_none());
+
+        let framework = "react-classic".parse::<Framework>().unwrap();
+        assert_eq!(framework, Framework::ReactClassic);
+        assert_eq!(framework.to_string(), "React (classic JSX runtime)");
+        assert!(matches!(framework.jsx_runtime(), JsxRuntime::ReactClassic));
+        assert_eq!(
+            "angular".parse::<Framework>().unwrap_err().to_string(),
+            "unknown framework `angular`"
+        );
+    }
+
+    #[test]
+    fn source_kind_of_the_rule_is_overridden() {
+        let meta = RuleMetadata::new("1.0.0", "noRedeclare", "Disallow redeclarations.")
+            .sources(&[
+                RuleSource::Eslint("no-redeclare"),
+                RuleSource::EslintTypeScript("no-redeclare"),
+            ])
+            .source_kind(RuleSourceKind::SameLogic);
+        let extra_metadata = parse(
+            r#"{ "rules": { "noRedeclare": { "sourceKinds": { "no-redeclare": "inspired" } } } }"#,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            extra_metadata.source_kind(&meta, &meta.sources[0]),
+            RuleSourceKind::Inspired
+        ));
+        assert!(matches!(
+            extra_metadata.source_kind(&meta, &meta.sources[1]),
+            RuleSourceKind::SameLogic
+        ));
+    }
+
+    #[test]
+    fn rule_names_are_checked_against_the_prefixes() {
+        let extra_metadata = ExtraMetadata::default();
+        assert!(extra_metadata.has_conventional_name("noDebugger"));
+        assert!(extra_metadata.has_conventional_name("useConst"));
+        assert!(!extra_metadata.has_conventional_name("nodebugger"));
+        assert!(!extra_metadata.has_conventional_name("avoidDebugger"));
+
+        let extra_metadata = parse(r#"{ "ruleNamePrefixes": ["avoid"] }"#).unwrap();
+        assert!(extra_metadata.has_conventional_name("avoidDebugger"));
+        assert!(!extra_metadata.has_conventional_name("noDebugger"));
+    }
+
+    #[test]
+    fn supersessions_link_both_rules() {
+        let extra_metadata =
+            parse(r#"{ "rules": { "noNewRule": { "supersedes": ["noOldRule"] } } }"#).unwrap();
+        assert_eq!(
+            extra_metadata.supersessions().collect::<Vec<_>>(),
+            [("noNewRule", "noOldRule")]
+        );
+        assert_eq!(extra_metadata.superseded_by("noOldRule"), ["noNewRule"]);
+        assert!(extra_metadata.superseded_by("noNewRule").is_empty());
+    }
+}
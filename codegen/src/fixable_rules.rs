@@ -0,0 +1,108 @@
+use crate::locale::Messages;
+use anyhow::Result;
+use biome_analyze::{FixKind, RuleMetadata};
+use biome_string_case::Case;
+use std::collections::BTreeMap;
+use std::io::Write;
+
+/// Generates the page that lists the rules providing a fix, with the kind of
+/// their fix. The rules without a fix and the unreleased rules aren't listed.
+pub(crate) fn generate_fixable_rules(
+    groups: &BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
+    messages: &Messages,
+) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+
+    writeln!(
+        buffer,
+        "---\ntitle: {}\ndescription: {}\n---\n",
+        messages.fixable_rules, messages.fixable_rules_description
+    )?;
+
+    writeln!(buffer, "{}", messages.fixable_rules_introduction)?;
+    writeln!(buffer)?;
+    writeln!(
+        buffer,
+        "| {} | {} | {} |",
+        messages.biome_rule_column, messages.group_column, messages.fix_column
+    )?;
+    writeln!(buffer, "| ---- | ---- | ---- |")?;
+    for (group, rules) in groups {
+        for (rule, meta) in rules {
+            if meta.version == "next" {
+                continue;
+            }
+            let fix = match meta.fix_kind {
+                Some(FixKind::Safe) => messages.safe_fix_value,
+                Some(FixKind::Unsafe) => messages.unsafe_fix_value,
+                _ => continue,
+            };
+            writeln!(
+                buffer,
+                "| [{rule}](/linter/rules/{}) | `{group}` | {fix} |",
+                Case::Kebab.convert(rule),
+            )?;
+        }
+    }
+
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::Locale;
+
+    #[test]
+    fn rules_with_a_fix_are_listed() {
+        let rule = |name| RuleMetadata::new("1.0.0", name, "").language("js");
+        let groups = BTreeMap::from([
+            (
+                "style",
+                BTreeMap::from([("useConst", rule("useConst").fix_kind(FixKind::Safe))]),
+            ),
+            (
+                "suspicious",
+                BTreeMap::from([
+                    ("noConsole", rule("noConsole")),
+                    ("noDebugger", rule("noDebugger").fix_kind(FixKind::Unsafe)),
+                ]),
+            ),
+        ]);
+
+        let buffer =
+            String::from_utf8(generate_fixable_rules(&groups, Locale::En.messages()).unwrap())
+                .unwrap();
+        let rows = buffer
+            .lines()
+            .filter(|line| line.starts_with("| ["))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            rows,
+            [
+                "| [useConst](/linter/rules/use-const) | `style` | safe |",
+                "| [noDebugger](/linter/rules/no-debugger) | `suspicious` | unsafe |",
+            ]
+        );
+    }
+
+    #[test]
+    fn page_is_localized() {
+        let groups = BTreeMap::from([(
+            "style",
+            BTreeMap::from([(
+                "useConst",
+                RuleMetadata::new("1.0.0", "useConst", "")
+                    .language("js")
+                    .fix_kind(FixKind::Unsafe),
+            )]),
+        )]);
+
+        let buffer =
+            String::from_utf8(generate_fixable_rules(&groups, Locale::Fr.messages()).unwrap())
+                .unwrap();
+        assert!(buffer.starts_with("---\ntitle: Règles avec un correctif\n"));
+        assert!(buffer.contains("| Règle Biome | Groupe | Correctif |\n"));
+        assert!(buffer.contains("| [useConst](/linter/rules/use-const) | `style` | non sûr |\n"));
+    }
+}
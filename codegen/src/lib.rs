@@ -1,12 +1,26 @@
+use crate::lintdoc::{docs_gen_config, DocsGenConfig};
 use bpaf::Bpaf;
 use std::env;
 use std::path::{Path, PathBuf};
 
+mod a11y_audit;
+mod cache;
+mod combined_html;
+mod eslint_migration;
+mod extra_metadata;
+mod fixable_rules;
 pub mod lintdoc;
+pub mod locale;
 pub mod metadata;
+pub mod registry_diff;
+pub mod renderer;
+mod rule_options;
 pub mod rules_sources;
 pub mod website;
 
+/// The version of `biome_analyze` the documentation is generated from
+pub const ANALYZER_VERSION: &str = env!("BIOME_ANALYZE_VERSION");
+
 pub fn project_root() -> PathBuf {
     Path::new(
         &env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| env!("CARGO_MANIFEST_DIR").to_owned()),
@@ -22,7 +36,26 @@ pub fn project_root() -> PathBuf {
 pub enum CodegenCommand {
     /// Updates the documentation of the rule pages
     #[bpaf(command)]
-    Rules,
+    Rules(#[bpaf(external(docs_gen_config))] DocsGenConfig),
+
+    /// Prints the documentation of a rule to the terminal
+    #[bpaf(command)]
+    Explain(
+        /// The name of the rule, for example `noDebugger`
+        #[bpaf(positional("RULE"))]
+        String,
+    ),
+
+    /// Compares the lint rules with a snapshot of a previous version of Biome
+    #[bpaf(command)]
+    RegistryDiff {
+        /// Replaces the snapshot with the current rules
+        #[bpaf(long("update"), switch)]
+        update: bool,
+        /// The path of the snapshot
+        #[bpaf(positional("SNAPSHOT"))]
+        snapshot: PathBuf,
+    },
 
     /// Metadata
     #[bpaf(command)]
@@ -34,5 +67,5 @@ pub enum CodegenCommand {
 
     /// Updates the documentation of the rule pages and the files of a release  
     #[bpaf(command)]
-    All,
+    All(#[bpaf(external(docs_gen_config))] DocsGenConfig),
 }
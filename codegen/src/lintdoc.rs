@@ -1,52 +1,743 @@
-use crate::project_root;
+use crate::a11y_audit::generate_a11y_audit;
+use crate::cache::{cache_file_path, CacheKey, CachedSnippet, RulePagesCache, SnippetsCache};
+use crate::combined_html::generate_combined_html;
+use crate::eslint_migration::generate_eslint_migration;
+use crate::extra_metadata::{rule_source_kind, ExtraMetadata, Framework, RuleExtraMetadata};
+use crate::fixable_rules::generate_fixable_rules;
+use crate::locale::{fill, Locale, Messages};
+use crate::renderer::{AsideType, PlainText, Renderer};
+use crate::rule_options::{EnumValue, RuleOptionsSchema};
 use crate::rules_sources::generate_rule_sources;
+use crate::{project_root, ANALYZER_VERSION};
 use anyhow::Context;
 use anyhow::{bail, ensure, Result};
 use biome_analyze::options::JsxRuntime;
 use biome_analyze::{
-    AnalysisFilter, AnalyzerOptions, ControlFlow, FixKind, GroupCategory, Queryable,
-    RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup, RuleMetadata, RuleSourceKind,
+    AnalysisFilter, AnalyzerAction, AnalyzerOptions, AnalyzerSignal, ControlFlow, FixKind,
+    GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleFilter, RuleGroup,
+    RuleMetadata, RuleSourceKind,
 };
+use biome_configuration::PartialConfiguration;
 use biome_console::fmt::Termcolor;
 use biome_console::{
     fmt::{Formatter, HTML},
     markup, Console, Markup, MarkupBuf,
 };
 use biome_css_parser::CssParserOptions;
-use biome_css_syntax::CssLanguage;
-use biome_diagnostics::termcolor::NoColor;
-use biome_diagnostics::{Diagnostic, DiagnosticExt, PrintDiagnostic};
+use biome_diagnostics::termcolor::{Ansi, NoColor};
+use biome_diagnostics::{
+    Applicability, Diagnostic, DiagnosticExt, PrintDescription, PrintDiagnostic, Severity,
+};
 use biome_js_parser::JsParserOptions;
-use biome_js_syntax::{EmbeddingKind, JsFileSource, JsLanguage, Language, ModuleKind};
+use biome_js_syntax::{EmbeddingKind, JsFileSource, Language, ModuleKind};
 use biome_json_parser::JsonParserOptions;
-use biome_json_syntax::JsonLanguage;
-use biome_service::settings::WorkspaceSettings;
+use biome_service::settings::{to_analyzer_rules, WorkspaceSettings};
+use biome_service::VERSION;
 use biome_string_case::Case;
+use bpaf::Bpaf;
 use pulldown_cmark::{html::write_html, CodeBlockKind, Event, LinkType, Parser, Tag, TagEnd};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::error::Error;
 use std::path::PathBuf;
 use std::{
-    collections::BTreeMap,
-    fmt::Write as _,
+    collections::{BTreeMap, BTreeSet},
+    fmt::{self, Write as _},
     fs,
     io::{self, Write as _},
+    mem,
+    ops::Range,
     path::Path,
     slice,
     str::{self, FromStr},
 };
 
-pub fn generate_rule_docs() -> Result<()> {
-    let root = project_root().join("src/content/docs/linter/rules");
+/// The default of [DocsGenConfig::diagnostic_class]
+const DEFAULT_DIAGNOSTIC_CLASS: &str = "language-text";
+
+/// The default of [DocsGenConfig::max_diagnostics]
+const DEFAULT_MAX_DIAGNOSTICS: usize = 20;
+
+/// The default of [DocsGenConfig::runaway_diagnostics], an example is a few
+/// lines long and expects at most one diagnostic
+const DEFAULT_RUNAWAY_DIAGNOSTICS: usize = 200;
+
+/// The default of [DocsGenConfig::max_docs_size], far above the size of the
+/// longest documentation of a rule
+const DEFAULT_MAX_DOCS_SIZE: usize = 64 * 1024;
+
+/// Options of the command that generates the rule pages
+#[derive(Debug, Clone, Bpaf)]
+pub struct DocsGenConfig {
+    /// Only generates the pages of the rules of the given language (`js`, `json` or `css`).
+    /// It can be passed multiple times. When a subset of the languages is selected,
+    /// the other rule pages and the aggregate files (index, groups, number of rules,
+    /// recommended rules, rules sources and ESLint migration) are left untouched.
+    #[bpaf(long("languages"), argument("LANGUAGE"))]
+    pub languages: Vec<RegistryLanguage>,
+
+    /// The languages whose lint rules can be documented
+    #[bpaf(pure(DOC_LANGUAGES))]
+    pub doc_languages: &'static [DocLanguage],
+
+    /// How the diagnostics of the examples are rendered: `html` (default) or `text`
+    #[bpaf(
+        long("diagnostic-backend"),
+        argument("BACKEND"),
+        fallback(DiagnosticBackend::Html)
+    )]
+    pub diagnostic_backend: DiagnosticBackend,
+
+    /// The class of the `<pre>` and `<code>` elements of the diagnostics
+    /// rendered as HTML, `language-text` by default
+    #[bpaf(
+        long("diagnostic-class"),
+        argument("CLASS"),
+        fallback(DEFAULT_DIAGNOSTIC_CLASS.to_string())
+    )]
+    pub diagnostic_class: String,
+
+    /// Reuses the pages of the rules whose inputs didn't change since the last
+    /// incremental run. The cache is stored in `--cache-dir`.
+    #[bpaf(long("incremental"), switch)]
+    pub incremental: bool,
+
+    /// The directory of the cache of the incremental runs, `codegen-cache`
+    /// inside the target directory by default
+    #[bpaf(
+        long("cache-dir"),
+        env("BIOME_CODEGEN_CACHE_DIR"),
+        argument("DIR"),
+        optional
+    )]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Only rewrites the aggregate files (index, groups, number of rules,
+    /// recommended rules, rules sources, ESLint migration and accessibility
+    /// audit), the pages of the rules are left untouched
+    #[bpaf(long("aggregates-only"), switch)]
+    pub aggregates_only: bool,
+
+    /// Checks that the generated pages can be parsed as MDX: braces must be
+    /// balanced and `<` can only start a known tag outside of code
+    #[bpaf(long("validate-mdx"), switch)]
+    pub validate_mdx: bool,
+
+    /// Checks that the icons referenced by the generated pages exist. Icons
+    /// other than the ones used by the generator can be allowed with the
+    /// `knownIcons` list of `codegen/extra-metadata.json`. The legend of the
+    /// index must describe exactly the icons used by the rules.
+    #[bpaf(long("validate-icons"), switch)]
+    pub validate_icons: bool,
+
+    /// Analyzes the examples of every rule and runs the checks, without
+    /// writing any file. Fails if any rule has an error. The cache of
+    /// `--incremental` is ignored, every page is generated.
+    #[bpaf(long("validate-only"), switch)]
+    pub validate_only: bool,
+
+    /// Adds a column to the index with the first invalid example of each
+    /// rule, when it fits on a single short line
+    #[bpaf(long("inline-examples"), switch)]
+    pub inline_examples: bool,
+
+    /// Adds a column to the index with the number of analyzed examples of
+    /// each rule
+    #[bpaf(long("example-counts"), switch)]
+    pub example_counts: bool,
+
+    /// Adds a `New` badge to the rules of the index introduced in the given
+    /// version, for example `1.9.0`
+    #[bpaf(
+        long("highlight-version"),
+        argument::<String>("VERSION"),
+        parse(|version| Version::parse(&version)),
+        optional
+    )]
+    pub highlight_version: Option<Version>,
+
+    /// Titles the examples that don't set a file with a file name matching
+    /// their language, for example `index.ts`
+    #[bpaf(long("default-file-titles"), switch)]
+    pub default_file_titles: bool,
+
+    /// The maximum number of diagnostics rendered or printed for a code
+    /// block, the diagnostics past the limit are summarized
+    #[bpaf(
+        long("max-diagnostics"),
+        argument("NUMBER"),
+        fallback(DEFAULT_MAX_DIAGNOSTICS)
+    )]
+    pub max_diagnostics: usize,
+
+    /// The number of diagnostics from which a code block fails as a runaway
+    /// rule, without printing its diagnostics
+    #[bpaf(
+        long("runaway-diagnostics"),
+        argument("NUMBER"),
+        fallback(DEFAULT_RUNAWAY_DIAGNOSTICS)
+    )]
+    pub runaway_diagnostics: usize,
+
+    /// Collapses the diagnostics longer than the given number of lines into
+    /// a `<details>` element
+    #[bpaf(long("collapse-diagnostics"), argument("LINES"), optional)]
+    pub collapse_diagnostics: Option<usize>,
+
+    /// The maximum size in bytes of the documentation of a rule. Larger
+    /// documentations fail the generation, as they're likely pasted by mistake.
+    #[bpaf(
+        long("max-docs-size"),
+        argument("BYTES"),
+        fallback(DEFAULT_MAX_DOCS_SIZE)
+    )]
+    pub max_docs_size: usize,
+
+    /// Fails the generation when the index of the rules is larger than the
+    /// given number of bytes
+    #[bpaf(long("max-index-size"), argument("BYTES"), optional)]
+    pub max_index_size: Option<usize>,
+
+    /// Fails the generation when the documentation of a rule has warnings,
+    /// for example a `TODO` left in its text. The unsafe fixes without
+    /// rationale are only reported.
+    #[bpaf(long("strict"), switch)]
+    pub strict: bool,
+
+    /// Writes the warnings of the documentation of the rules to a JSON file,
+    /// besides printing them
+    #[bpaf(long("warnings-report"), argument("PATH"), optional)]
+    pub warnings_report: Option<PathBuf>,
+
+    /// Also writes every rule page into a single HTML document, ready to be
+    /// printed or exported to PDF
+    #[bpaf(long("combined-html"), argument("PATH"), optional)]
+    pub combined_html: Option<PathBuf>,
+
+    /// The release channel the pages describe: `stable` (default) or `nightly`.
+    /// Nursery rules are enabled by default on nightly builds.
+    #[bpaf(long("channel"), argument("CHANNEL"), fallback(Channel::Stable))]
+    pub channel: Channel,
+
+    /// Also generates the pages of the rules that aren't released yet
+    /// (version `next`), marked as such, for the previews of the site
+    #[bpaf(long("include-unreleased"), switch)]
+    pub include_unreleased: bool,
+
+    /// The aside the `Why is this bad?` sections of the rules are rendered in:
+    /// `note`, `tip` (default), `caution` or `danger`
+    #[bpaf(long("rationale-aside"), argument("ASIDE"), fallback(AsideType::Tip))]
+    pub rationale_aside: AsideType,
+
+    /// The language of the text around the documentation of the rules:
+    /// `en` (default) or `fr`
+    #[bpaf(long("locale"), argument("LOCALE"), fallback(Locale::En))]
+    pub locale: Locale,
+
+    /// A `biome.json` whose linter settings, like the severities of the rules,
+    /// are used to analyze the examples instead of the defaults
+    #[bpaf(
+        long("configuration"),
+        argument::<PathBuf>("PATH"),
+        parse(load_pinned_configuration),
+        optional
+    )]
+    pub configuration: Option<PartialConfiguration>,
+
+    /// Renders the constructs specific to the framework of the site, Starlight
+    /// by default
+    #[bpaf(pure(Renderer::default()))]
+    pub renderer: Renderer,
+}
+
+impl Default for DocsGenConfig {
+    fn default() -> Self {
+        Self {
+            languages: Vec::new(),
+            doc_languages: DOC_LANGUAGES,
+            diagnostic_backend: DiagnosticBackend::default(),
+            diagnostic_class: DEFAULT_DIAGNOSTIC_CLASS.to_string(),
+            incremental: false,
+            cache_dir: None,
+            aggregates_only: false,
+            validate_mdx: false,
+            validate_icons: false,
+            validate_only: false,
+            inline_examples: false,
+            example_counts: false,
+            highlight_version: None,
+            default_file_titles: false,
+            max_diagnostics: DEFAULT_MAX_DIAGNOSTICS,
+            runaway_diagnostics: DEFAULT_RUNAWAY_DIAGNOSTICS,
+            collapse_diagnostics: None,
+            max_docs_size: DEFAULT_MAX_DOCS_SIZE,
+            max_index_size: None,
+            strict: false,
+            warnings_report: None,
+            combined_html: None,
+            channel: Channel::default(),
+            include_unreleased: false,
+            rationale_aside: AsideType::default(),
+            locale: Locale::default(),
+            configuration: None,
+            renderer: Renderer::default(),
+        }
+    }
+}
+
+/// Loads the configuration passed to `--configuration`
+fn load_pinned_configuration(path: PathBuf) -> Result<PartialConfiguration, String> {
+    let content = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    serde_json::from_str(&content)
+        .map_err(|err| format!("failed to parse {}: {err}", path.display()))
+}
+
+impl DocsGenConfig {
+    /// Whether the rules of the given registry should be generated
+    fn includes(&self, language: &DocLanguage) -> bool {
+        self.languages.is_empty()
+            || self
+                .languages
+                .iter()
+                .any(|included| included.0 == language.name)
+    }
+
+    /// Whether only a subset of the registries is generated
+    fn is_subset(&self) -> bool {
+        self.doc_languages
+            .iter()
+            .any(|language| !self.includes(language))
+    }
+
+    /// Returns the language of the metadata of the rules with the given name
+    fn rule_language(&self, name: &str) -> Option<&'static RuleLanguage> {
+        self.doc_languages
+            .iter()
+            .flat_map(|language| language.rule_languages)
+            .find(|language| language.name == name)
+    }
+}
+
+/// A language that has a registry of lint rules, selected with `--languages`
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct RegistryLanguage(&'static str);
+
+impl FromStr for RegistryLanguage {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        DOC_LANGUAGES
+            .iter()
+            .find(|language| language.name == input)
+            .map(|language| Self(language.name))
+            .ok_or_else(|| {
+                let names = DOC_LANGUAGES
+                    .iter()
+                    .map(|language| format!("`{}`", language.name))
+                    .collect::<Vec<_>>();
+                format!(
+                    "unknown language `{input}`, expected one of {}",
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// A language whose lint rules are documented: how its registry is visited,
+/// how the code blocks of its examples are recognized, labelled and analyzed,
+/// and how its rules are described
+pub struct DocLanguage {
+    /// The name of the language in `--languages`
+    name: &'static str,
+    /// The languages of the metadata of the rules of the registry
+    rule_languages: &'static [RuleLanguage],
+    /// The tokens of the info strings of the code blocks in the language,
+    /// each with the token of the blocks it's analyzed like
+    tokens: &'static [(&'static str, &'static str)],
+    /// The module kind of the code blocks of a token, `None` when the
+    /// language doesn't have modules and can't use `esm` or `script`
+    module_kind: fn(&str) -> Option<ModuleKind>,
+    /// Collects the lint rules of the registry of the language
+    visit_registry: fn(&mut LintRulesVisitor),
+    /// The language of a code block on the page, without the lintdoc-specific
+    /// attributes
+    label: fn(&CodeSource) -> String,
+    /// Parses and analyzes the code of an example
+    analyze: fn(&mut Analysis, &CodeSource, &str) -> Result<()>,
+}
+
+impl fmt::Debug for DocLanguage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DocLanguage")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DocLanguage {
+    /// Returns the source of a code block marked with the token, if it's one
+    /// of the language
+    fn code_source(&'static self, token: &str) -> Option<CodeSource> {
+        self.tokens
+            .iter()
+            .find(|(alias, _)| *alias == token)
+            .map(|&(_, token)| CodeSource {
+                language: self,
+                token,
+                module_kind: None,
+            })
+    }
+}
+
+/// A language of the metadata of the rules. A registry can have rules of
+/// several ones, like the JavaScript rules that only apply to TypeScript.
+struct RuleLanguage {
+    name: &'static str,
+    /// The files the rules are applied to, as described on their pages
+    description: fn(&Messages) -> &'static str,
+    /// The icon of the rules in the index
+    icon: &'static str,
+    /// The label of the icon
+    label: fn(&Messages) -> &'static str,
+    /// What the icon stands for in the legend of the index, `None` when the
+    /// icon is shared with another language that explains it
+    legend: fn(&Messages) -> Option<&'static str>,
+    /// The file types the rules are applied to, exposed in the frontmatter
+    file_types: &'static [&'static str],
+}
+
+/// The languages whose lint rules are documented, in the order their
+/// registries are visited
+const DOC_LANGUAGES: &[DocLanguage] = &[
+    DocLanguage {
+        name: "js",
+        rule_languages: &[
+            RuleLanguage {
+                name: "js",
+                description: |messages| messages.js_files,
+                icon: "seti:javascript",
+                label: |messages| messages.js_label,
+                legend: |messages| Some(messages.js_legend),
+                file_types: &["js", "jsx", "ts", "tsx"],
+            },
+            RuleLanguage {
+                name: "jsx",
+                description: |messages| messages.jsx_files,
+                icon: "seti:javascript",
+                label: |messages| messages.jsx_label,
+                legend: |_| None,
+                file_types: &["jsx", "tsx"],
+            },
+            RuleLanguage {
+                name: "ts",
+                description: |messages| messages.ts_files,
+                icon: "seti:typescript",
+                label: |messages| messages.ts_label,
+                legend: |messages| Some(messages.ts_legend),
+                file_types: &["ts", "tsx"],
+            },
+        ],
+        // The same list of extensions as `compute_source_type_from_path_or_extension`
+        tokens: &[
+            ("cjs", "cjs"),
+            ("js", "jsx"),
+            ("mjs", "jsx"),
+            ("jsx", "jsx"),
+            ("ts", "ts"),
+            ("mts", "ts"),
+            ("cts", "ts"),
+            ("tsx", "tsx"),
+            ("svelte", "svelte"),
+            ("astro", "astro"),
+            ("vue", "vue"),
+        ],
+        module_kind: |token| Some(js_file_source(token).module_kind()),
+        visit_registry: biome_js_analyze::visit_registry::<LintRulesVisitor>,
+        label: js_label,
+        analyze: analyze_js,
+    },
+    DocLanguage {
+        name: "json",
+        rule_languages: &[RuleLanguage {
+            name: "json",
+            description: |_| "JSON",
+            icon: "seti:json",
+            label: |messages| messages.json_label,
+            legend: |messages| Some(messages.json_legend),
+            file_types: &["json"],
+        }],
+        tokens: &[("json", "json")],
+        module_kind: |_| None,
+        visit_registry: biome_json_analyze::visit_registry::<LintRulesVisitor>,
+        label: |source| source.token.to_string(),
+        analyze: analyze_json,
+    },
+    DocLanguage {
+        name: "css",
+        rule_languages: &[RuleLanguage {
+            name: "css",
+            description: |_| "CSS",
+            icon: "seti:css",
+            label: |messages| messages.css_label,
+            legend: |messages| Some(messages.css_legend),
+            file_types: &["css"],
+        }],
+        tokens: &[("css", "css")],
+        module_kind: |_| None,
+        visit_registry: biome_css_analyze::visit_registry::<LintRulesVisitor>,
+        label: |source| source.token.to_string(),
+        analyze: analyze_css,
+    },
+];
+
+/// How the diagnostics of the examples are written in the pages
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum DiagnosticBackend {
+    /// HTML markup, colored using the classes of `biome_console`
+    #[default]
+    Html,
+    /// Plain text without colors
+    Text,
+    /// Text colored with ANSI escape codes, used to print the documentation
+    /// of a rule to the terminal
+    Terminal,
+}
+
+impl DiagnosticBackend {
+    /// Prints the diagnostic into the buffer
+    fn print(self, buffer: &mut Vec<u8>, diagnostic: &biome_diagnostics::Error) -> io::Result<()> {
+        match self {
+            Self::Html => Formatter::new(&mut HTML(buffer)).write_markup(markup! {
+                {PrintDiagnostic::verbose(diagnostic)}
+            }),
+            Self::Text => {
+                Formatter::new(&mut Termcolor(NoColor::new(buffer))).write_markup(markup! {
+                    {PrintDiagnostic::verbose(diagnostic)}
+                })
+            }
+            Self::Terminal => {
+                Formatter::new(&mut Termcolor(Ansi::new(buffer))).write_markup(markup! {
+                    {PrintDiagnostic::verbose(diagnostic)}
+                })
+            }
+        }
+    }
+
+    /// Opens the block that contains the diagnostics of an example. The
+    /// class is given to the `<pre>` and `<code>` elements of HTML blocks.
+    fn open_block(self, buffer: &mut Vec<u8>, class: &str) -> io::Result<()> {
+        match self {
+            Self::Html => write!(buffer, "<pre class=\"{class}\"><code class=\"{class}\">"),
+            Self::Text => writeln!(buffer, "```text"),
+            Self::Terminal => Ok(()),
+        }
+    }
+
+    /// Closes the block opened by [DiagnosticBackend::open_block]
+    fn close_block(self, buffer: &mut Vec<u8>) -> io::Result<()> {
+        match self {
+            Self::Html => writeln!(buffer, "</code></pre>"),
+            Self::Text => {
+                if !buffer.ends_with(b"\n") {
+                    writeln!(buffer)?;
+                }
+                writeln!(buffer, "```")
+            }
+            Self::Terminal => Ok(()),
+        }
+    }
+}
+
+impl DiagnosticBackend {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Text => "text",
+            Self::Terminal => "terminal",
+        }
+    }
+}
+
+impl FromStr for DiagnosticBackend {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "html" => Ok(Self::Html),
+            "text" => Ok(Self::Text),
+            _ => Err(format!(
+                "unknown diagnostic backend `{input}`, expected `html` or `text`"
+            )),
+        }
+    }
+}
+
+/// The release channel of Biome described by the pages
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Channel {
+    /// Nursery rules require an explicit opt-in
+    #[default]
+    Stable,
+    /// Nursery rules are enabled by default
+    Nightly,
+}
+
+impl Channel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Nightly => "nightly",
+        }
+    }
+}
+
+impl FromStr for Channel {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "stable" => Ok(Self::Stable),
+            "nightly" => Ok(Self::Nightly),
+            _ => Err(format!(
+                "unknown channel `{input}`, expected `stable` or `nightly`"
+            )),
+        }
+    }
+}
+
+/// Collects the metadata of the lint rules of the registries, by group
+#[derive(Default)]
+struct LintRulesVisitor {
+    groups: BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
+    /// The languages of each rule, a rule can be registered by several registries
+    languages: BTreeMap<&'static str, Vec<&'static str>>,
+}
+
+impl LintRulesVisitor {
+    /// Collects the rules of the registries of the languages that are
+    /// included in the generation
+    fn visit(config: &DocsGenConfig) -> Self {
+        let mut visitor = Self::default();
+        for language in config.doc_languages {
+            if config.includes(language) {
+                (language.visit_registry)(&mut visitor);
+            }
+        }
+        visitor
+    }
+
+    fn record_language(&mut self, metadata: &RuleMetadata) {
+        let languages = self.languages.entry(metadata.name).or_default();
+        if !languages.contains(&metadata.language) {
+            languages.push(metadata.language);
+        }
+    }
+}
+
+impl<L: biome_rowan::Language> RegistryVisitor<L> for LintRulesVisitor {
+    fn record_category<C: GroupCategory<Language = L>>(&mut self) {
+        if matches!(C::CATEGORY, RuleCategory::Lint) {
+            C::record_groups(self);
+        }
+    }
+
+    fn record_rule<R>(&mut self)
+    where
+        R: Rule + 'static,
+        R::Query: Queryable<Language = L>,
+        <R::Query as Queryable>::Output: Clone,
+    {
+        self.record_language(&R::METADATA);
+        self.groups
+            .entry(<R::Group as RuleGroup>::NAME)
+            .or_default()
+            .insert(R::METADATA.name, R::METADATA);
+    }
+}
+
+pub fn generate_rule_docs(config: &DocsGenConfig) -> Result<()> {
+    let visitor = LintRulesVisitor::visit(config);
+    let extra_metadata = ExtraMetadata::load()?;
+
+    write_rule_docs(visitor, &extra_metadata, config, &project_root())
+}
+
+/// Checks that the generated pages of the rules have different titles, two
+/// rules with the same name in different groups would have the same one
+fn validate_unique_titles(pages: &[(&str, &str, String)]) -> Result<()> {
+    let mut pages_by_title = BTreeMap::<&str, Vec<String>>::new();
+    for (group, rule, content) in pages {
+        let title = content
+            .strip_prefix("---\n")
+            .and_then(|frontmatter| frontmatter.split("\n---\n").next())
+            .and_then(|frontmatter| {
+                frontmatter
+                    .lines()
+                    .find_map(|line| line.strip_prefix("title: "))
+            });
+        if let Some(title) = title {
+            pages_by_title
+                .entry(title)
+                .or_default()
+                .push(format!("{rule} ({group})"));
+        }
+    }
+
+    let collisions = pages_by_title
+        .into_iter()
+        .filter(|(_, pages)| pages.len() > 1)
+        .map(|(title, pages)| format!("- `{title}`: {}", pages.join(", ")))
+        .collect::<Vec<_>>();
+    ensure!(
+        collisions.is_empty(),
+        "these pages of the rules have the same title:\n{}",
+        collisions.join("\n")
+    );
+
+    Ok(())
+}
+
+/// Writes the pages of the rules collected by the visitor, and the files that
+/// list them, into the site whose root directory is `output_root`
+fn write_rule_docs(
+    visitor: LintRulesVisitor,
+    extra_metadata: &ExtraMetadata,
+    config: &DocsGenConfig,
+    output_root: &Path,
+) -> Result<()> {
+    let root = output_root.join("src/content/docs/linter/rules");
     let index_page = root.join("index.mdx");
-    let reference_groups = project_root().join("src/components/generated/Groups.astro");
-    let rules_sources = project_root().join("src/content/docs/linter/rules-sources.mdx");
+    let reference_groups = output_root.join("src/components/generated/Groups.astro");
+    let rules_sources = output_root.join("src/content/docs/linter/rules-sources.mdx");
+    let eslint_migration = output_root.join("src/content/docs/linter/eslint-migration.mdx");
+    let a11y_audit = output_root.join("src/content/docs/linter/accessibility-audit.mdx");
+    let fixable_rules = output_root.join("src/content/docs/linter/fixable-rules.mdx");
     let reference_number_of_rules =
-        project_root().join("src/components/generated/NumberOfRules.astro");
+        output_root.join("src/components/generated/NumberOfRules.astro");
     let reference_recommended_rules =
-        project_root().join("src/components/generated/RecommendedRules.astro");
-    // Clear the rules directory ignoring "not found" errors
+        output_root.join("src/components/generated/RecommendedRules.astro");
+    let reference_analyzer_version =
+        output_root.join("src/components/generated/AnalyzerVersion.astro");
+    let reference_legend = output_root.join("src/components/generated/RulesLegend.json");
+    let reference_recommended_rules_by_group =
+        output_root.join("src/components/generated/RecommendedRulesByGroup.astro");
+    let reference_recommended_configuration =
+        output_root.join("src/components/generated/RecommendedConfiguration.json");
+    ensure!(
+        !(config.aggregates_only && config.is_subset()),
+        "`--aggregates-only` can't be used with `--languages`, the aggregate files list the rules of every language"
+    );
+    ensure!(
+        !(config.aggregates_only && config.combined_html.is_some()),
+        "`--aggregates-only` can't be used with `--combined-html`, the pages of the rules aren't generated"
+    );
+
+    // Clear the rules directory ignoring "not found" errors. The pages of
+    // the languages that aren't generated are kept when working on a subset.
 
-    if root.exists() {
+    if root.exists() && !config.is_subset() && !config.aggregates_only && !config.validate_only {
         if let Err(err) = fs::remove_dir_all(&root) {
             let is_not_found = err
                 .source()
@@ -58,148 +749,286 @@ pub fn generate_rule_docs() -> Result<()> {
             }
         }
     }
-    fs::create_dir_all(&root)?;
+    if !config.validate_only {
+        fs::create_dir_all(&root)?;
+        fs::create_dir_all(output_root.join("src/components/generated"))?;
+    }
 
     // Content of the index page
+    let messages = config.locale.messages();
     let mut index = Vec::new();
     let mut reference_buffer = Vec::new();
     writeln!(index, "---")?;
-    writeln!(index, "title: Rules")?;
-    writeln!(index, "description: List of available lint rules.")?;
+    writeln!(index, "title: {}", messages.rules)?;
+    writeln!(index, "description: {}", messages.rules_description)?;
     writeln!(index, "---")?;
     writeln!(index)?;
 
-    write!(
+    let renderer = &config.renderer;
+    writeln!(index)?;
+    writeln!(
+        index,
+        r#"import RecommendedRules from "@/components/generated/RecommendedRules.astro";"#
+    )?;
+    writeln!(
+        index,
+        r#"import AnalyzerVersion from "@/components/generated/AnalyzerVersion.astro";"#
+    )?;
+    writeln!(
         index,
-        r#"
-import RecommendedRules from "@/components/generated/RecommendedRules.astro";
-import {{ Icon }} from "@astrojs/starlight/components";
-
-Below the list of rules supported by Biome, divided by group. Here's a legend of the emojis:
-- The icon <span class='inline-icon'><Icon name="approve-check-circle" label="This rule is recommended" /></span> indicates that the rule is part of the recommended rules.
-- The icon <span class='inline-icon'><Icon name="seti:config" label="The rule has a safe fix" /></span> indicates that the rule provides a code action (fix) that is **safe** to apply.
-- The icon <span class='inline-icon'><Icon name="warning" label="The rule has an unsafe fix" /></span> indicates that the rule provides a code action (fix) that is **unsafe** to apply.
-- The icon <span class='inline-icon'><Icon name="seti:javascript" label="JavaScript and super languages rule" /></span> indicates that the rule is applied to JavaScript and super languages files.
-- The icon <span class='inline-icon'><Icon name="seti:typescript" label="TypeScript rule" /></span> indicates that the rule is applied to TypeScript and TSX files.
-- The icon <span class='inline-icon'><Icon name="seti:json" label="JSON rule" /></span> indicates that the rule is applied to JSON files.
-"#
+        r#"import RecommendedRulesByGroup from "@/components/generated/RecommendedRulesByGroup.astro";"#
     )?;
+    renderer.write_index_imports(&mut index)?;
+    writeln!(index)?;
+    writeln!(index, "{}", messages.rules_introduction)?;
+    // The icons link to the section that explains what they stand for, when
+    // there's one
+    let recommended_rules_anchor = format!("#{}", heading_anchor(messages.recommended_rules, &[]));
+    let mut legend = vec![
+        (
+            "approve-check-circle",
+            messages.recommended_label,
+            messages.recommended_legend,
+            Some(recommended_rules_anchor.as_str()),
+        ),
+        (
+            "seti:config",
+            messages.safe_fix_label,
+            messages.safe_fix_legend,
+            Some("/linter/#safe-fixes"),
+        ),
+        (
+            "warning",
+            messages.unsafe_fix_label,
+            messages.unsafe_fix_legend,
+            Some("/linter/#unsafe-fixes"),
+        ),
+    ];
+    for language in config
+        .doc_languages
+        .iter()
+        .flat_map(|language| language.rule_languages)
+    {
+        if let Some(legend_text) = (language.legend)(messages) {
+            legend.push((language.icon, (language.label)(messages), legend_text, None));
+        }
+    }
+    if config.channel == Channel::Nightly {
+        legend.push((
+            "moon",
+            messages.nightly_label,
+            messages.nightly_legend,
+            None,
+        ));
+    }
+    // The legend is written once the rows are known, it only describes the
+    // icons they use
+    let legend_position = index.len();
+
+    let options_schema = RuleOptionsSchema::new();
+    // The pages are always generated when they're only validated, a cached
+    // page would hide the problems of its documentation
+    let cache_path = cache_file_path(config.cache_dir.as_deref());
+    let mut cache =
+        (config.incremental && !config.validate_only).then(|| RulePagesCache::load(&cache_path));
 
     // Accumulate errors for all lint rules to print all outstanding issues on
     // failure instead of just the first one
     let mut errors = Vec::new();
+    // The rules and the reasons of their examples that aren't analyzed
+    let mut ignored_examples = Vec::new();
+    // The rules and the problems of their documentation that don't prevent
+    // the generation of their page
+    let mut warnings = Vec::new();
 
-    #[derive(Default)]
-    struct LintRulesVisitor {
-        groups: BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
-        number_or_rules: u16,
-    }
+    let mut recommended_rules = String::new();
+    // The generated pages of the rules, in the order of the index
+    let mut generated_pages = Vec::new();
 
-    impl RegistryVisitor<JsLanguage> for LintRulesVisitor {
-        fn record_category<C: GroupCategory<Language = JsLanguage>>(&mut self) {
-            if matches!(C::CATEGORY, RuleCategory::Lint) {
-                C::record_groups(self);
-            }
-        }
+    let LintRulesVisitor {
+        mut groups,
+        languages,
+    } = visitor;
 
-        fn record_rule<R>(&mut self)
-        where
-            R: Rule + 'static,
-            R::Query: Queryable<Language = JsLanguage>,
-            <R::Query as Queryable>::Output: Clone,
-        {
-            self.number_or_rules += 1;
-            self.groups
-                .entry(<R::Group as RuleGroup>::NAME)
-                .or_default()
-                .insert(R::METADATA.name, R::METADATA);
-        }
+    // Empty registries, for example when the analyzer is built without its
+    // rules, still produce a valid index
+    if groups.is_empty() {
+        warnings.push((
+            "registries",
+            Warning::new(
+                WarningKind::EmptyRegistry,
+                "no lint rules were found, the index of the rules is empty",
+            ),
+        ));
     }
 
-    impl RegistryVisitor<JsonLanguage> for LintRulesVisitor {
-        fn record_category<C: GroupCategory<Language = JsonLanguage>>(&mut self) {
-            if matches!(C::CATEGORY, RuleCategory::Lint) {
-                C::record_groups(self);
-            }
-        }
+    // A group added to the analyzer needs a name and a description
+    let unknown_groups = groups
+        .keys()
+        .filter(|group| extract_group_metadata(group).is_none())
+        .map(|group| format!("`{group}`"))
+        .collect::<Vec<_>>();
+    ensure!(
+        unknown_groups.is_empty(),
+        "the groups {} don't have metadata, add them to `extract_group_metadata`",
+        unknown_groups.join(", ")
+    );
 
-        fn record_rule<R>(&mut self)
-        where
-            R: Rule + 'static,
-            R::Query: Queryable<Language = JsonLanguage>,
-            <R::Query as Queryable>::Output: Clone,
-        {
-            self.number_or_rules += 1;
-            self.groups
-                .entry(<R::Group as RuleGroup>::NAME)
-                .or_default()
-                .insert(R::METADATA.name, R::METADATA);
+    // A rule in several groups would overwrite the page of the other one,
+    // before the titles of the pages could be compared
+    let mut groups_by_rule = BTreeMap::<&str, Vec<&str>>::new();
+    for (group, rules) in &groups {
+        for rule in rules.keys() {
+            groups_by_rule.entry(rule).or_default().push(group);
         }
     }
+    let duplicated_rules = groups_by_rule
+        .iter()
+        .filter(|(_, groups)| groups.len() > 1)
+        .map(|(rule, groups)| format!("{rule} ({})", groups.join(", ")))
+        .collect::<Vec<_>>();
+    ensure!(
+        duplicated_rules.is_empty(),
+        "these rules are in several groups, their pages would have the same title: {}",
+        duplicated_rules.join(", ")
+    );
 
-    impl RegistryVisitor<CssLanguage> for LintRulesVisitor {
-        fn record_category<C: GroupCategory<Language = CssLanguage>>(&mut self) {
-            if matches!(C::CATEGORY, RuleCategory::Lint) {
-                C::record_groups(self);
-            }
-        }
-
-        fn record_rule<R>(&mut self)
-        where
-            R: Rule + 'static,
-            R::Query: Queryable<Language = CssLanguage>,
-            <R::Query as Queryable>::Output: Clone,
-        {
-            self.number_or_rules += 1;
-            self.groups
-                .entry(<R::Group as RuleGroup>::NAME)
-                .or_default()
-                .insert(R::METADATA.name, R::METADATA);
-        }
+    // The rules of the other languages are missing from a subset
+    if !config.is_subset() {
+        let unknown_rules = extra_metadata
+            .supersessions()
+            .filter(|(rule, superseded)| {
+                !groups_by_rule.contains_key(rule) || !groups_by_rule.contains_key(superseded)
+            })
+            .map(|(rule, superseded)| format!("`{rule}` supersedes `{superseded}`"))
+            .collect::<Vec<_>>();
+        ensure!(
+            unknown_rules.is_empty(),
+            "`supersedes` in `codegen/extra-metadata.json` references rules that don't exist: {}",
+            unknown_rules.join(", ")
+        );
     }
 
-    let mut visitor = LintRulesVisitor::default();
-    biome_js_analyze::visit_registry(&mut visitor);
-    biome_json_analyze::visit_registry(&mut visitor);
-    biome_css_analyze::visit_registry(&mut visitor);
-
-    let mut recommended_rules = String::new();
-
-    let LintRulesVisitor {
-        mut groups,
-        number_or_rules,
-    } = visitor;
+    // The number of rules is the number of rows of the index: a rule
+    // registered by several registries is counted once, and the unreleased
+    // rules are only listed with `--include-unreleased`
+    let number_of_rules = groups
+        .values()
+        .flat_map(|rules| rules.values())
+        .filter(|meta| config.include_unreleased || meta.version != "next")
+        .count();
 
-    let nursery_rules = groups
-        .remove("nursery")
-        .expect("Expected nursery group to exist");
+    // The nursery group is rendered last, it can be missing when every
+    // nursery rule has been promoted
+    let nursery_rules = groups.remove("nursery");
 
     writeln!(
         reference_buffer,
         "<!-- this file is auto generated, use `cargo lintdoc` to update it -->"
     )?;
-    let rule_sources_buffer = generate_rule_sources(groups.clone())?;
+    let rule_sources_buffer = generate_rule_sources(groups.clone(), extra_metadata, renderer)?;
+    let eslint_migration_buffer = generate_eslint_migration(groups.clone(), extra_metadata)?;
+    let recommended_rules_by_group_buffer = generate_recommended_rules_by_group(&groups, messages);
+    let recommended_configuration_buffer = generate_recommended_configuration(&groups)?;
+    let fixable_rules_buffer = generate_fixable_rules(&groups, messages)?;
+    let a11y_audit_buffer = generate_a11y_audit(
+        groups.get("a11y").unwrap_or(&BTreeMap::new()),
+        extra_metadata,
+        &mut warnings,
+    )?;
+    let expected_recommended_rules = groups
+        .values()
+        .flat_map(|rules| rules.values())
+        .filter(|meta| meta.recommended)
+        .map(|meta| meta.name)
+        .collect::<BTreeSet<_>>();
     for (group, rules) in groups {
         generate_group(
             group,
             rules,
             &root,
+            config,
+            extra_metadata,
+            &options_schema,
+            &languages,
+            cache.as_mut(),
             &mut index,
             &mut errors,
+            &mut ignored_examples,
+            &mut warnings,
             &mut recommended_rules,
+            &mut generated_pages,
         )?;
         generate_reference(group, &mut reference_buffer)?;
     }
 
-    generate_group(
-        "nursery",
-        nursery_rules,
-        &root,
-        &mut index,
-        &mut errors,
-        &mut recommended_rules,
-    )?;
-    generate_reference("nursery", &mut reference_buffer)?;
+    if let Some(nursery_rules) = nursery_rules {
+        generate_group(
+            "nursery",
+            nursery_rules,
+            &root,
+            config,
+            extra_metadata,
+            &options_schema,
+            &languages,
+            cache.as_mut(),
+            &mut index,
+            &mut errors,
+            &mut ignored_examples,
+            &mut warnings,
+            &mut recommended_rules,
+            &mut generated_pages,
+        )?;
+        generate_reference("nursery", &mut reference_buffer)?;
+    }
+    if let Some(cache) = &cache {
+        cache.save(&cache_path)?;
+    }
+    validate_unique_titles(&generated_pages)?;
+    if !ignored_examples.is_empty() {
+        let problems = ignored_examples
+            .iter()
+            .map(|(rule, reason)| (*rule, reason.as_str()));
+        eprint!(
+            "{}",
+            rule_problems(messages.ignored_examples, problems, messages)
+        );
+    }
+    if let Some(path) = config
+        .warnings_report
+        .as_ref()
+        .filter(|_| !config.validate_only)
+    {
+        write_warnings_report(path, &warnings)?;
+    }
+    if let Some(path) = config
+        .combined_html
+        .as_ref()
+        .filter(|_| !config.validate_only)
+    {
+        fs::write(path, generate_combined_html(&generated_pages)?)
+            .with_context(|| format!("failed to write the combined rules {}", path.display()))?;
+    }
+    if config.strict {
+        let (failing, reported): (Vec<_>, Vec<_>) = warnings
+            .into_iter()
+            .partition(|(_, warning)| warning.kind.fails_strict());
+        errors.extend(
+            failing
+                .into_iter()
+                .map(|(rule, warning)| (rule, anyhow::anyhow!(warning))),
+        );
+        warnings = reported;
+    }
+    if !warnings.is_empty() {
+        let problems = warnings
+            .iter()
+            .map(|(rule, warning)| (*rule, warning.message.as_str()));
+        eprint!(
+            "{}",
+            rule_problems(messages.rule_warnings, problems, messages)
+        );
+    }
     if !errors.is_empty() {
         bail!(
             "failed to generate documentation pages for the following rules:\n{}",
@@ -211,6 +1040,53 @@ Below the list of rules supported by Biome, divided by group. Here's a legend of
                 })
         );
     }
+
+    let used_icons = index_icons(str::from_utf8(&index)?, renderer)?;
+    if config.validate_icons {
+        validate_legend(
+            &used_icons,
+            &legend.iter().map(|&(icon, ..)| icon).collect(),
+        )?;
+    }
+    legend.retain(|(icon, ..)| used_icons.contains(icon));
+    let mut legend_lines = Vec::new();
+    for &(icon, label, explanation, link) in &legend {
+        let icon = renderer.icon(icon, label);
+        match link {
+            Some(link) => writeln!(
+                legend_lines,
+                "- {} [{icon}]({link}) {explanation}",
+                messages.legend_icon
+            )?,
+            None => writeln!(
+                legend_lines,
+                "- {} {icon} {explanation}",
+                messages.legend_icon
+            )?,
+        }
+    }
+    index.splice(legend_position..legend_position, legend_lines);
+    if config.validate_icons {
+        validate_icons(str::from_utf8(&index)?, extra_metadata, config)
+            .context("the index of the rules references an unknown icon")?;
+    }
+
+    // The aggregate files list every rule, writing them from a subset of
+    // the registries would drop the rules of the other languages
+    if config.is_subset() {
+        return Ok(());
+    }
+
+    validate_recommended_rules(&recommended_rules, &expected_recommended_rules)?;
+    let listed_rules = str::from_utf8(&index)?
+        .lines()
+        .filter(|line| is_rule_row(line))
+        .count();
+    ensure!(
+        listed_rules == number_of_rules,
+        "the index lists {listed_rules} rules, but the number of rules is {number_of_rules}"
+    );
+
     let recommended_rules_buffer = format!(
         "<!-- this file is auto generated, use `cargo lintdoc` to update it -->\n \
     <ul>\n{}\n</ul>",
@@ -218,23 +1094,85 @@ Below the list of rules supported by Biome, divided by group. Here's a legend of
     );
 
     let number_of_rules_buffer = format!(
-        "<!-- this file is auto generated, use `cargo lintdoc` to update it -->\n{number_or_rules}"
+        "<!-- this file is auto generated, use `cargo lintdoc` to update it -->\n{number_of_rules}"
     );
     write!(
         index,
         "
-## Recommended rules
+## {}
 
-The recommended rules are:
+{}
 
 <RecommendedRules />
-"
+
+{}
+
+<RecommendedRulesByGroup />
+
+{}
+
+```json
+{recommended_configuration_buffer}
+```
+
+{}
+",
+        messages.recommended_rules,
+        messages.recommended_rules_list,
+        messages.recommended_rules_by_group,
+        messages.recommended_configuration,
+        fill(
+            messages.analyzer_version,
+            &[("version", "<AnalyzerVersion />")]
+        ),
+    )?;
+    // The legend, for the tools that display the icons of the rules
+    let legend_buffer = serde_json::to_string_pretty(
+        &legend
+            .iter()
+            .map(|&(icon, label, explanation, link)| {
+                serde_json::json!({
+                    "icon": icon,
+                    "label": label,
+                    "explanation": explanation,
+                    "link": link,
+                })
+            })
+            .collect::<Vec<_>>(),
     )?;
+    if let Some(max_index_size) = config.max_index_size {
+        ensure!(
+            index.len() <= max_index_size,
+            "the index of the rules is {} bytes long, more than the limit of {max_index_size} bytes, consider splitting it",
+            index.len()
+        );
+    }
+    if config.validate_only {
+        return Ok(());
+    }
     fs::write(index_page, index)?;
     fs::write(reference_groups, reference_buffer)?;
+    fs::write(reference_legend, legend_buffer)?;
     fs::write(reference_number_of_rules, number_of_rules_buffer)?;
     fs::write(reference_recommended_rules, recommended_rules_buffer)?;
+    fs::write(
+        reference_recommended_rules_by_group,
+        recommended_rules_by_group_buffer,
+    )?;
+    fs::write(
+        reference_recommended_configuration,
+        recommended_configuration_buffer,
+    )?;
+    fs::write(
+        reference_analyzer_version,
+        format!(
+            "<!-- this file is auto generated, use `cargo lintdoc` to update it -->\n{ANALYZER_VERSION}\n"
+        ),
+    )?;
     fs::write(rules_sources, rule_sources_buffer)?;
+    fs::write(eslint_migration, eslint_migration_buffer)?;
+    fs::write(a11y_audit, a11y_audit_buffer)?;
+    fs::write(fixable_rules, fixable_rules_buffer)?;
 
     Ok(())
 }
@@ -243,190 +1181,997 @@ fn generate_group(
     group: &'static str,
     rules: BTreeMap<&'static str, RuleMetadata>,
     root: &Path,
+    config: &DocsGenConfig,
+    extra_metadata: &ExtraMetadata,
+    options_schema: &RuleOptionsSchema,
+    languages: &BTreeMap<&'static str, Vec<&'static str>>,
+    mut cache: Option<&mut RulePagesCache>,
     main_page_buffer: &mut dyn io::Write,
     errors: &mut Vec<(&'static str, anyhow::Error)>,
+    ignored_examples: &mut Vec<(&'static str, String)>,
+    warnings: &mut Vec<(&'static str, Warning)>,
     recommended_rules: &mut String,
+    generated_pages: &mut Vec<(&'static str, &'static str, String)>,
 ) -> io::Result<()> {
-    let (group_name, description) = extract_group_metadata(group);
+    let (group_name, description) =
+        extract_group_metadata(group).expect("the groups are checked before the generation");
     let is_nursery = group == "nursery";
+    let messages = config.locale.messages();
 
-    writeln!(main_page_buffer, "\n## {group_name}")?;
+    writeln!(
+        main_page_buffer,
+        "\n## <span class=\"rule-group\" data-group=\"{group}\">{group_name}</span>"
+    )?;
     writeln!(main_page_buffer)?;
     write_markup_to_string(main_page_buffer, description)?;
     writeln!(main_page_buffer)?;
-    writeln!(main_page_buffer, "| Rule name | Description | Properties |")?;
-    writeln!(main_page_buffer, "| --- | --- | --- |")?;
+    let mut header = format!(
+        "| {} | {} | {} |",
+        messages.rule_name, messages.description, messages.properties
+    );
+    let mut separator = String::from("| --- | --- | --- |");
+    if config.inline_examples {
+        header.push_str(&format!(" {} |", messages.example));
+        separator.push_str(" --- |");
+    }
+    if config.example_counts {
+        header.push_str(&format!(" {} |", messages.examples));
+        separator.push_str(" --- |");
+    }
+    writeln!(main_page_buffer, "{header}")?;
+    writeln!(main_page_buffer, "{separator}")?;
 
     for (rule, meta) in rules {
-        // We don't document rules that haven't been released yet
+        let is_recommended = !is_nursery && meta.recommended;
+        // We don't document rules that haven't been released yet, unless
+        // the pages are generated for a preview
         if meta.version == "next" {
-            continue;
+            // Only released rules can be recommended, unreleased rules start
+            // in the nursery
+            if is_recommended {
+                errors.push((
+                    rule,
+                    anyhow::anyhow!(
+                        "the rule is recommended but it isn't released yet (version `next`)"
+                    ),
+                ));
+                continue;
+            }
+            if !config.include_unreleased {
+                continue;
+            }
         }
-        let is_recommended = !is_nursery && meta.recommended;
         let dashed_rule = Case::Kebab.convert(rule);
+        // The URL of a page must be mapped back to its rule
+        if Case::Camel.convert(&dashed_rule) != rule {
+            errors.push((
+                rule,
+                anyhow::anyhow!(
+                    "the slug `{dashed_rule}` of the rule doesn't convert back to the name of the rule"
+                ),
+            ));
+            continue;
+        }
+        if !extra_metadata.has_conventional_name(rule) {
+            warnings.push((
+                rule,
+                Warning::new(
+                    WarningKind::UnconventionalName,
+                    "the name of the rule doesn't start with one of the expected prefixes, see `ruleNamePrefixes` in `codegen/extra-metadata.json`",
+                ),
+            ));
+        }
+        if meta.docs.len() > config.max_docs_size {
+            errors.push((
+                rule,
+                anyhow::anyhow!(
+                    "the documentation of the rule is {} bytes long, more than the limit of {} bytes",
+                    meta.docs.len(),
+                    config.max_docs_size
+                ),
+            ));
+            continue;
+        }
         if is_recommended {
             recommended_rules.push_str(&format!(
                 "\t<li><a href='/linter/rules/{dashed_rule}'>{rule}</a></li>\n"
             ));
         }
 
-        match generate_rule(GenRule {
-            root,
-            group,
-            rule,
-            is_recommended,
-            meta: &meta,
-        }) {
-            Ok(summary) => {
-                let mut properties = String::new();
-                if is_recommended {
-                    properties.push_str("<span class='inline-icon'><Icon name=\"approve-check-circle\" size=\"1.2rem\" label=\"This rule is recommended\" /></span>");
+        let rule_languages = &languages[rule];
+        let entry = if config.aggregates_only {
+            // The pages are left untouched, so their examples aren't analyzed
+            match index_entry(meta.docs, config) {
+                Ok(entry) => entry,
+                Err(err) => {
+                    errors.push((rule, err));
+                    continue;
                 }
-
-                match meta.fix_kind {
-                    Some(FixKind::Safe) => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"seti:config\" label=\"The rule has a safe fix\" size=\"1.2rem\"  /></span>");
-                    }
-                    Some(FixKind::Unsafe) => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"warning\" label=\"The rule has an unsafe fix\" size=\"1.2rem\" /></span>");
+            }
+        } else {
+            let extra = extra_metadata.rule(rule);
+            let superseded_by = extra_metadata.superseded_by(rule);
+            let cache_key = rule_page_cache_key(
+                group,
+                &meta,
+                rule_languages,
+                is_recommended,
+                extra,
+                &superseded_by,
+                config,
+            );
+            let cached_page = cache
+                .as_deref()
+                .and_then(|cache| cache.get(rule, cache_key))
+                .cloned();
+            let page = match cached_page {
+                Some(page) => Ok(page),
+                None => {
+                    let mut snippets = cache.as_deref_mut().map(|cache| cache.take_snippets(rule));
+                    let page = generate_rule(GenRule {
+                        group,
+                        rule,
+                        is_recommended,
+                        meta: &meta,
+                        languages: rule_languages,
+                        extra,
+                        superseded_by: &superseded_by,
+                        options_schema,
+                        snippets: snippets.as_mut(),
+                        config,
+                    });
+                    if let (Some(cache), Some(snippets)) = (cache.as_deref_mut(), snippets) {
+                        cache.insert_snippets(rule, snippets);
                     }
-                    _ => {}
+                    page
                 }
+            };
 
-                match meta.language {
-                    "js" => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"seti:javascript\" label=\"JavaScript and super languages rule.\" size=\"1.2rem\"/></span>");
+            match page {
+                Ok(page) => {
+                    if !config.validate_only {
+                        fs::write(root.join(format!("{dashed_rule}.md")), &page.content)?;
                     }
-                    "jsx" => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"seti:javascript\" label=\"JSX rule\" size=\"1.2rem\"/></span>");
+                    generated_pages.push((group, rule, page.content.clone()));
+                    if config.validate_mdx {
+                        if let Err(err) = validate_mdx(&page.content) {
+                            errors.push((rule, err));
+                        }
                     }
-                    "ts" => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"seti:typescript\" label=\"TypeScript rule\" size=\"1.2rem\"/></span>");
+                    if config.validate_icons {
+                        if let Err(err) = validate_icons(&page.content, extra_metadata, config) {
+                            errors.push((rule, err));
+                        }
                     }
-                    "json" => {
-                        properties.push_str("<span class='inline-icon'><Icon name=\"seti:json\" label=\"JSON rule\" size=\"1.2rem\"/></span>");
+                    if let Some(cache) = cache.as_deref_mut() {
+                        cache.insert(rule, cache_key, page.clone());
                     }
-                    _ => {
-                        eprintln!("Language {} isn't supported.", meta.language)
+
+                    let RulePage {
+                        summary,
+                        ignore_reasons,
+                        short_example,
+                        analyzed_example_count,
+                        warnings: page_warnings,
+                        ..
+                    } = page;
+                    ignored_examples
+                        .extend(ignore_reasons.into_iter().map(|reason| (rule, reason)));
+                    warnings.extend(page_warnings.into_iter().map(|warning| (rule, warning)));
+
+                    IndexEntry {
+                        summary,
+                        short_example,
+                        analyzed_example_count,
                     }
                 }
+                Err(err) => {
+                    errors.push((rule, err));
+                    continue;
+                }
+            }
+        };
+        let IndexEntry {
+            summary,
+            short_example,
+            analyzed_example_count,
+        } = entry;
 
-                let mut summary_html = Vec::new();
-                write_html(&mut summary_html, summary.into_iter())?;
-                let summary_html = String::from_utf8_lossy(&summary_html);
-                write!(
-                    main_page_buffer,
-                    "| [{rule}](/linter/rules/{dashed_rule}) | {summary_html} | {properties} |"
-                )?;
+        let mut properties = String::new();
+        if is_recommended {
+            properties.push_str(
+                &config
+                    .renderer
+                    .icon("approve-check-circle", messages.recommended_label),
+            );
+        }
+        if is_nursery && config.channel == Channel::Nightly {
+            properties.push_str(&config.renderer.icon("moon", messages.nightly_label));
+        }
 
-                writeln!(main_page_buffer)?;
+        match meta.fix_kind {
+            Some(FixKind::Safe) => {
+                properties.push_str(&config.renderer.icon("seti:config", messages.safe_fix_label));
+            }
+            Some(FixKind::Unsafe) => {
+                properties.push_str(&config.renderer.icon("warning", messages.unsafe_fix_label));
             }
-            Err(err) => {
-                errors.push((rule, err));
+            _ => {}
+        }
+
+        // The unsupported languages are reported by the page of the rule
+        for language in rule_languages {
+            if let Some(language) = config.rule_language(language) {
+                properties.push_str(
+                    &config
+                        .renderer
+                        .icon(language.icon, (language.label)(messages)),
+                );
             }
         }
+
+        let is_highlighted = config.highlight_version.as_ref().is_some_and(|version| {
+            Version::parse(meta.version).is_ok_and(|rule_version| rule_version == *version)
+        });
+        let badge = if meta.version == "next" {
+            format!(
+                " <span class=\"new-rule-badge\">{}</span>",
+                messages.unreleased_badge
+            )
+        } else if is_highlighted {
+            format!(
+                " <span class=\"new-rule-badge\">{}</span>",
+                messages.new_badge
+            )
+        } else {
+            String::new()
+        };
+        write!(
+            main_page_buffer,
+            "| <span class=\"rule-group\" data-group=\"{group}\">[{rule}](/linter/rules/{dashed_rule})</span>{badge} | {summary} | {properties} |"
+        )?;
+        if config.inline_examples {
+            let example = short_example
+                .map(|example| format!("<code>{}</code>", escape_table_cell(&example)))
+                .unwrap_or_default();
+            write!(main_page_buffer, " {example} |")?;
+        }
+        if config.example_counts {
+            write!(main_page_buffer, " {analyzed_example_count} |")?;
+        }
+
+        writeln!(main_page_buffer)?;
     }
 
     Ok(())
 }
 
-struct GenRule<'a> {
-    root: &'a Path,
-    group: &'static str,
+/// The icons of Starlight that the generator references, besides the icons
+/// of the languages of the rules
+const KNOWN_ICONS: &[&str] = &["approve-check-circle", "moon", "seti:config", "warning"];
+
+/// Checks that every icon of the content references a known icon
+fn validate_icons(
+    content: &str,
+    extra_metadata: &ExtraMetadata,
+    config: &DocsGenConfig,
+) -> Result<()> {
+    let is_language_icon = |name: &str| {
+        config
+            .doc_languages
+            .iter()
+            .flat_map(|language| language.rule_languages)
+            .any(|language| language.icon == name)
+    };
+    for (index, line) in content.lines().enumerate() {
+        let names = config
+            .renderer
+            .icon_names(line)
+            .with_context(|| format!("line {} contains an unterminated icon", index + 1))?;
+        for name in names {
+            ensure!(
+                KNOWN_ICONS.contains(&name)
+                    || is_language_icon(name)
+                    || extra_metadata.is_known_icon(name),
+                "line {} references the unknown icon `{name}`",
+                index + 1
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether the line of the index is the row of a rule. The name of a rule is
+/// wrapped in a `rule-group` span, which the site uses to color-code the
+/// groups.
+fn is_rule_row(line: &str) -> bool {
+    line.starts_with("| <span class=\"rule-group\"")
+}
+
+/// Returns the icons used by the rows of the rules of the index
+fn index_icons<'a>(index: &'a str, renderer: &Renderer) -> Result<BTreeSet<&'a str>> {
+    let mut icons = BTreeSet::new();
+    for line in index.lines().filter(|line| is_rule_row(line)) {
+        icons.extend(renderer.icon_names(line)?);
+    }
+    Ok(icons)
+}
+
+/// Checks that the legend describes the icons used by the rows of the rules.
+/// The icons that no row uses are left out of the legend, they're not errors.
+fn validate_legend(used: &BTreeSet<&str>, legend: &BTreeSet<&str>) -> Result<()> {
+    let missing = used.difference(legend).collect::<Vec<_>>();
+    ensure!(
+        missing.is_empty(),
+        "the rules of the index use the icons {missing:?}, which the legend doesn't describe"
+    );
+
+    Ok(())
+}
+
+/// The HTML tags and components that the generated pages are allowed to contain
+const KNOWN_MDX_TAGS: &[&str] = &[
+    "a", "br", "code", "details", "div", "em", "Icon", "li", "p", "pre", "small", "span", "strong",
+    "summary", "ul",
+];
+
+/// Checks that the content can be parsed as MDX, which is stricter than
+/// Markdown: outside of code, braces start JavaScript expressions and `<`
+/// starts a JSX element.
+fn validate_mdx(content: &str) -> Result<()> {
+    let mut in_code_block = false;
+    let mut brace_depth = 0usize;
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        // Inline code spans are the odd segments when splitting on backticks,
+        // only the even ones are checked
+        for segment in line.split('`').step_by(2) {
+            let mut chars = segment.char_indices().peekable();
+            while let Some((position, char)) = chars.next() {
+                match char {
+                    '{' => brace_depth += 1,
+                    '}' => {
+                        brace_depth = brace_depth.checked_sub(1).with_context(|| {
+                            format!("line {line_number} closes a brace that was never opened")
+                        })?;
+                    }
+                    '<' if chars
+                        .peek()
+                        .map_or(false, |(_, next)| next.is_ascii_alphabetic()) =>
+                    {
+                        let tag: String = segment[position + 1..]
+                            .chars()
+                            .take_while(|char| char.is_ascii_alphanumeric())
+                            .collect();
+                        ensure!(
+                            KNOWN_MDX_TAGS.contains(&tag.as_str()),
+                            "line {line_number} contains the unknown tag `<{tag}`, it should be escaped or put in code"
+                        );
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    ensure!(
+        brace_depth == 0,
+        "the page has {brace_depth} unclosed brace(s)"
+    );
+
+    Ok(())
+}
+
+/// Computes the key of the page of a rule in the cache, from everything the
+/// page is generated from. The versions of the analyzer and of the generator
+/// are part of the key, so that the examples are analyzed again when either
+/// changes.
+fn rule_page_cache_key(
+    group: &str,
+    meta: &RuleMetadata,
+    languages: &[&str],
+    is_recommended: bool,
+    extra: Option<&RuleExtraMetadata>,
+    superseded_by: &[&str],
+    config: &DocsGenConfig,
+) -> u64 {
+    let mut key = CacheKey::new();
+    key.str(group)
+        .str(meta.name)
+        .str(meta.version)
+        .str(meta.docs)
+        .list(languages)
+        .bool(is_recommended)
+        .str(match meta.fix_kind {
+            Some(FixKind::Safe) => "safe",
+            Some(FixKind::Unsafe) => "unsafe",
+            _ => "none",
+        })
+        .option(meta.source_kind.as_ref().map(|kind| match kind {
+            RuleSourceKind::SameLogic => "same-logic",
+            RuleSourceKind::Inspired => "inspired",
+        }));
+    key.u64(meta.sources.len() as u64);
+    for source in meta.sources {
+        key.str(&source.to_namespaced_rule_name())
+            .str(&source.to_rule_url());
+    }
+    key.json(&extra).list(superseded_by);
+    hash_generator(config, &mut key);
+    key.finish()
+}
+
+/// Computes the key of the analysis of an example in the cache: the example,
+/// the rule and its options, and the generator with its configuration, as the
+/// cached diagnostics are rendered.
+fn snippet_cache_key(
+    group: &str,
+    rule: &str,
+    info_string: &str,
+    code: &str,
+    options: Option<&str>,
+    has_fix_kind: bool,
+    frameworks: &[Framework],
+    config: &DocsGenConfig,
+) -> u64 {
+    let mut key = CacheKey::new();
+    key.str(group)
+        .str(rule)
+        .str(info_string)
+        .str(code)
+        .option(options)
+        .bool(has_fix_kind)
+        .json(&frameworks);
+    hash_generator(config, &mut key);
+    key.finish()
+}
+
+/// The version of the output of the generator. Bump it when a change of the
+/// generator changes the pages generated from the same rules, so that the
+/// pages and the examples cached by incremental runs are generated again.
+const GENERATOR_VERSION: u32 = 2;
+
+/// Hashes what every cached page and example depends on besides the rule:
+/// the versions of the generator and of the analyzer, the renderer, and the
+/// options that change the pages, including the pinned `biome.json`. The
+/// options that only select, check or report, like `--strict`, aren't part
+/// of the key.
+fn hash_generator(config: &DocsGenConfig, key: &mut CacheKey) {
+    let doc_languages = config
+        .doc_languages
+        .iter()
+        .map(|language| language.name)
+        .collect::<Vec<_>>();
+    key.u64(u64::from(GENERATOR_VERSION))
+        .str(ANALYZER_VERSION)
+        .str(config.renderer.name())
+        .list(&doc_languages)
+        .str(config.diagnostic_backend.as_str())
+        .str(&config.diagnostic_class)
+        .bool(config.default_file_titles)
+        .u64(config.max_diagnostics as u64)
+        .bool(config.collapse_diagnostics.is_some())
+        .u64(config.collapse_diagnostics.unwrap_or_default() as u64)
+        .str(config.channel.as_str())
+        .str(config.rationale_aside.as_str())
+        .str(config.locale.as_str())
+        .json(&config.configuration);
+}
+
+/// A generated rule page and what the index needs to know about it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct RulePage {
+    /// The content of the page
+    content: String,
+    /// The summary of the rule, rendered as HTML
+    summary: String,
+    /// The reasons of the ignored examples, listed after the generation
+    ignore_reasons: Vec<String>,
+    /// The first invalid example, when it's short enough to be inlined in
+    /// the index
+    #[serde(default)]
+    short_example: Option<String>,
+    /// The number of examples that are analyzed
+    #[serde(default)]
+    analyzed_example_count: usize,
+    /// Kept with the cached page, so that an incremental run reports the
+    /// warnings of the rules it doesn't regenerate
+    #[serde(default)]
+    warnings: Vec<Warning>,
+}
+
+/// A problem of the documentation of a rule that doesn't prevent the
+/// generation of its page
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Warning {
+    kind: WarningKind,
+    message: String,
+}
+
+/// The kinds of [Warning], exposed in the report written by `--warnings-report`
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum WarningKind {
+    /// The rule is registered for a language the pages don't describe
+    UnsupportedLanguage,
+    /// The documentation has invalid examples but no valid example
+    MissingValidExample,
+    /// The documentation contains a `TODO`, `FIXME` or `XXX` marker
+    UnfinishedMarker,
+    /// The accessibility rule isn't mapped to any accessibility criterion
+    MissingAccessibilityCriteria,
+    /// The name of the rule doesn't start with one of the expected prefixes
+    UnconventionalName,
+    /// The performance rule doesn't describe the runtime cost of the pattern
+    /// it reports
+    MissingPerformanceImpact,
+    /// The label rendered for a code block doesn't parse back to its language
+    LossyLanguageLabel,
+    /// The registries don't have any lint rule
+    EmptyRegistry,
+    /// The summary of the rule is too short or restates the name of the rule
+    UninformativeSummary,
+    /// The extra metadata of the rule sets the kind of a source the rule
+    /// doesn't have
+    UnknownSource,
+    /// The rule has an unsafe fix, but doesn't explain why it's unsafe
+    MissingUnsafeFixRationale,
+    /// A heading of the documentation is more than one level deeper than the
+    /// previous one
+    SkippedHeadingLevel,
+    /// A code block has an unknown attribute after its language
+    UnknownAttribute,
+}
+
+impl WarningKind {
+    /// Whether the warning fails the generation with `--strict`. A missing
+    /// `unsafeFixRationale` is only reported, the rationales are added to
+    /// `codegen/extra-metadata.json` progressively.
+    fn fails_strict(self) -> bool {
+        !matches!(self, Self::MissingUnsafeFixRationale)
+    }
+}
+
+impl Warning {
+    pub(crate) fn new(kind: WarningKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+/// An entry of the report written by `--warnings-report`
+#[derive(Serialize)]
+struct WarningReportEntry<'a> {
+    rule: &'a str,
+    kind: WarningKind,
+    message: &'a str,
+}
+
+/// Writes the warnings as a JSON array of `{ "rule", "kind", "message" }`
+fn write_warnings_report(path: &Path, warnings: &[(&str, Warning)]) -> Result<()> {
+    let entries = warnings
+        .iter()
+        .map(|(rule, warning)| WarningReportEntry {
+            rule,
+            kind: warning.kind,
+            message: &warning.message,
+        })
+        .collect::<Vec<_>>();
+    fs::write(path, serde_json::to_string_pretty(&entries)?)
+        .with_context(|| format!("failed to write the warnings report {}", path.display()))
+}
+
+/// Lists the problems of the rules below the heading, one line per problem
+fn rule_problems<'a>(
+    heading: &str,
+    problems: impl Iterator<Item = (&'a str, &'a str)>,
+    messages: &Messages,
+) -> String {
+    let mut report = format!("{heading}\n");
+    for (rule, problem) in problems {
+        report.push_str(&fill(
+            messages.rule_problem,
+            &[("rule", rule), ("problem", problem)],
+        ));
+        report.push('\n');
+    }
+    report
+}
+
+/// The maximum length of the examples inlined in the index
+const MAX_SHORT_EXAMPLE_LENGTH: usize = 60;
+
+/// Returns the example trimmed, if it's short enough to be inlined in the index
+fn short_example(example: String) -> Option<String> {
+    let example = example.trim();
+    (!example.contains('\n') && example.chars().count() <= MAX_SHORT_EXAMPLE_LENGTH)
+        .then(|| example.to_string())
+}
+
+/// What the index shows of a rule, besides its metadata
+struct IndexEntry {
+    summary: String,
+    short_example: Option<String>,
+    analyzed_example_count: usize,
+}
+
+/// Extracts the entry of a rule in the index from its documentation, like
+/// [generate_rule] does, without analyzing its examples
+fn index_entry(docs: &str, config: &DocsGenConfig) -> Result<IndexEntry> {
+    let mut summary = Vec::new();
+    let mut is_summary = false;
+    let mut canonical_example = None;
+    let mut analyzed_example_count = 0;
+    // The code of the current code block, if it expects a diagnostic
+    let mut invalid_example: Option<String> = None;
+
+    for event in Parser::new(docs) {
+        if is_summary {
+            if matches!(event, Event::End(TagEnd::Paragraph)) {
+                is_summary = false;
+            } else {
+                summary.push(event.clone());
+            }
+        }
+
+        match event {
+            Event::Start(Tag::Paragraph) if summary.is_empty() => is_summary = true,
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(meta))) => {
+                let test = CodeBlockTest::parse(meta.as_ref(), config.doc_languages)?;
+                if !test.ignore && test.parse_only_reason.is_none() {
+                    analyzed_example_count += 1;
+                }
+                if test.expect_diagnostic {
+                    invalid_example = Some(String::new());
+                }
+            }
+            Event::Text(text) => {
+                if let Some(example) = &mut invalid_example {
+                    example.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::CodeBlock) => {
+                if let Some(example) = invalid_example.take() {
+                    canonical_example.get_or_insert(example);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut html = Vec::new();
+    write_html(&mut html, summary.into_iter())?;
+
+    Ok(IndexEntry {
+        summary: String::from_utf8(html)?,
+        short_example: canonical_example.and_then(short_example),
+        analyzed_example_count,
+    })
+}
+
+/// Escapes the text so that it can be put in the quoted `title` of the info
+/// string of a code block
+fn escape_fence_title(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Escapes the text so that it can be put in a cell of a table of an MDX page
+fn escape_table_cell(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '{' => escaped.push_str("&#123;"),
+            '}' => escaped.push_str("&#125;"),
+            '|' => escaped.push_str("&#124;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The frontmatter keys written by the generator, which the extra
+/// frontmatter of a rule can't override
+const RESERVED_FRONTMATTER_KEYS: &[&str] = &[
+    "title",
+    "description",
+    "stability",
+    "fixable",
+    "languages",
+    "recommended",
+];
+
+/// Writes the entries as YAML. Objects become nested mappings, while the
+/// other values are written as JSON, which is valid YAML.
+fn write_frontmatter_entries(
+    content: &mut Vec<u8>,
+    entries: &Map<String, Value>,
+    indentation: usize,
+) -> io::Result<()> {
+    for (key, value) in entries {
+        let indentation_str = " ".repeat(indentation);
+        match value {
+            Value::Object(entries) => {
+                writeln!(content, "{indentation_str}{key}:")?;
+                write_frontmatter_entries(content, entries, indentation + 2)?;
+            }
+            _ => writeln!(content, "{indentation_str}{key}: {value}")?,
+        }
+    }
+
+    Ok(())
+}
+
+struct GenRule<'a> {
+    group: &'static str,
     rule: &'static str,
     is_recommended: bool,
     meta: &'a RuleMetadata,
+    /// The languages of the registries that registered the rule
+    languages: &'a [&'static str],
+    extra: Option<&'a RuleExtraMetadata>,
+    /// The rules that list the rule in their `supersedes`
+    superseded_by: &'a [&'a str],
+    options_schema: &'a RuleOptionsSchema,
+    /// The analyzed examples of the rule, on incremental runs
+    snippets: Option<&'a mut SnippetsCache>,
+    config: &'a DocsGenConfig,
 }
 
 /// Generates the documentation page for a single lint rule
-fn generate_rule(payload: GenRule) -> Result<Vec<Event<'static>>> {
+fn generate_rule(payload: GenRule) -> Result<RulePage> {
     let GenRule {
-        root,
         group,
         rule,
         is_recommended,
         meta,
+        languages,
+        extra,
+        superseded_by,
+        options_schema,
+        snippets,
+        config,
     } = payload;
     let mut content = Vec::new();
+    let mut warnings = Vec::new();
+    let messages = config.locale.messages();
+
+    for language in languages {
+        if config.rule_language(language).is_none() {
+            warnings.push(Warning::new(
+                WarningKind::UnsupportedLanguage,
+                format!("the language `{language}` isn't supported"),
+            ));
+        }
+    }
 
     let title_version = if meta.version == "next" {
-        "(not released)".to_string()
+        messages.not_released.to_string()
     } else {
-        format!("(since v{})", meta.version)
+        fill(messages.since_version, &[("version", meta.version)])
     };
     // Write the header for this lint rule
     writeln!(content, "---")?;
     writeln!(content, "title: {rule} {title_version}")?;
+    // Structured properties of the rule, used to filter the rules
+    let stability = if group == "nursery" {
+        "nursery"
+    } else {
+        "stable"
+    };
+    writeln!(content, "stability: {stability}")?;
+    let fixable = match meta.fix_kind {
+        Some(FixKind::Safe) => "safe",
+        Some(FixKind::Unsafe) => "unsafe",
+        _ => "none",
+    };
+    writeln!(content, "fixable: {fixable}")?;
+    let mut file_types = Vec::new();
+    for &language in languages {
+        let language_file_types = config
+            .rule_language(language)
+            .map_or(slice::from_ref(&language), |language| language.file_types);
+        for file_type in language_file_types {
+            if !file_types.contains(file_type) {
+                file_types.push(*file_type);
+            }
+        }
+    }
+    writeln!(content, "languages: [{}]", file_types.join(", "))?;
+    writeln!(content, "recommended: {is_recommended}")?;
+    if let Some(extra) = extra {
+        for key in RESERVED_FRONTMATTER_KEYS {
+            ensure!(
+                !extra.frontmatter.contains_key(*key),
+                "the extra frontmatter of the rule can't override the reserved key `{key}`"
+            );
+        }
+        write_frontmatter_entries(&mut content, &extra.frontmatter, 0)?;
+    }
     writeln!(content, "---")?;
     writeln!(content)?;
 
-    write!(content, "**Diagnostic Category: `lint/{group}/{rule}`**")?;
+    // The category is exposed as structured data, so that the site can add a
+    // button that copies it. Its anchor, e.g. `#lint-suspicious-no-debugger`,
+    // lets other pages link to the definition of the category.
+    let category_anchor = format!("lint-{group}-{}", Case::Kebab.convert(rule));
+    write!(
+        content,
+        "<p id=\"{category_anchor}\" data-category=\"lint/{group}/{rule}\"><strong>{} <a href=\"#{category_anchor}\"><code>lint/{group}/{rule}</code></a></strong></p>",
+        messages.diagnostic_category
+    )?;
     writeln!(content)?;
 
     writeln!(content)?;
 
-    if is_recommended || !matches!(meta.fix_kind, None) {
-        writeln!(content, ":::note")?;
+    let option_defaults = options_schema
+        .rule_options(group, rule)
+        .map(|options| options_schema.scalar_defaults(options))
+        .unwrap_or_default();
+    if is_recommended || !matches!(meta.fix_kind, None) || !option_defaults.is_empty() {
+        config
+            .renderer
+            .open_aside(&mut content, AsideType::Note, None)?;
         if is_recommended {
-            writeln!(content, "- This rule is recommended by Biome. A diagnostic error will appear when linting your code.")?;
+            writeln!(content, "- {}", messages.recommended)?;
         }
         match meta.fix_kind {
             Some(FixKind::Safe) => {
-                writeln!(content, "- This rule has a **safe** fix.")?;
+                writeln!(content, "- {}", messages.safe_fix)?;
             }
             Some(FixKind::Unsafe) => {
-                writeln!(content, "- This rule has an **unsafe** fix.")?;
+                writeln!(content, "- {}", messages.unsafe_fix)?;
+                match extra.and_then(|extra| extra.unsafe_fix_rationale.as_deref()) {
+                    // The rationale continues the item of the list
+                    Some(rationale) => {
+                        for line in rationale.trim_end().lines() {
+                            writeln!(content, "  {line}")?;
+                        }
+                    }
+                    None => {
+                        warnings.push(Warning::new(
+                            WarningKind::MissingUnsafeFixRationale,
+                            "the rule has an unsafe fix but no `unsafeFixRationale` in `codegen/extra-metadata.json`",
+                        ));
+                    }
+                }
             }
             _ => {}
         }
-        match meta.language {
-            "js" => {
-                writeln!(
-                    content,
-                    "- This rule is applied to **JavaScript and super languages** files."
-                )?;
-            }
-            "jsx" => {
-                writeln!(content, "- This rule is applied to **JSX and TSX** files.")?;
-            }
-            "ts" => {
-                writeln!(
-                    content,
-                    "- This rule is applied to **TypeScript and TSX** files."
-                )?;
-            }
-            "json" => {
-                writeln!(content, "- This rule is applied to **JSON** files.")?;
-            }
-            _ => {
-                eprintln!("Language {} isn't supported.", meta.language)
-            }
+        let descriptions = languages
+            .iter()
+            .filter_map(|language| config.rule_language(language))
+            .map(|language| (language.description)(messages))
+            .map(|description| format!("**{description}**"))
+            .collect::<Vec<_>>();
+        if !descriptions.is_empty() {
+            writeln!(
+                content,
+                "- {}",
+                fill(
+                    messages.applied_to_files,
+                    &[("languages", descriptions.join(messages.and).as_str())]
+                )
+            )?;
+        }
+        if !option_defaults.is_empty() {
+            let option_defaults = option_defaults
+                .iter()
+                .map(|(option, default)| {
+                    fill(
+                        messages.option_default,
+                        &[("option", *option), ("default", default.as_str())],
+                    )
+                })
+                .collect::<Vec<_>>();
+            writeln!(
+                content,
+                "- {}",
+                fill(
+                    messages.by_default,
+                    &[("options", option_defaults.join(", ").as_str())]
+                )
+            )?;
         }
-        writeln!(content, ":::")?;
+        config.renderer.close_aside(&mut content)?;
         writeln!(content)?;
     }
 
     if group == "nursery" {
-        writeln!(content, ":::caution")?;
-        writeln!(
-            content,
-            "This rule is part of the [nursery](/linter/rules/#nursery) group."
-        )?;
-        writeln!(content, ":::")?;
+        config
+            .renderer
+            .open_aside(&mut content, AsideType::Caution, None)?;
+        writeln!(content, "{}", messages.nursery_group)?;
+        if config.channel == Channel::Nightly {
+            // The severity on nightly builds reflects whether the rule is
+            // meant to be recommended once it's stabilized
+            let severity = if meta.recommended {
+                messages.nursery_nightly_error
+            } else {
+                messages.nursery_nightly_warning
+            };
+            writeln!(content, "{severity}")?;
+        } else {
+            writeln!(content, "{}", messages.nursery_stable)?;
+        }
+        config.renderer.close_aside(&mut content)?;
+        writeln!(content)?;
+    }
+    if meta.version == "next" {
+        config
+            .renderer
+            .open_aside(&mut content, AsideType::Caution, None)?;
+        writeln!(content, "{}", messages.unreleased)?;
+        config.renderer.close_aside(&mut content)?;
+        writeln!(content)?;
+    }
+    if extra.is_some_and(|extra| extra.formatter_overlap) {
+        config
+            .renderer
+            .open_aside(&mut content, AsideType::Note, None)?;
+        writeln!(content, "{}", messages.formatter_overlap)?;
+        config.renderer.close_aside(&mut content)?;
         writeln!(content)?;
     }
+    let frameworks = extra.map_or(&[][..], |extra| extra.frameworks.as_slice());
+    if !frameworks.is_empty() {
+        let frameworks = frameworks
+            .iter()
+            .map(|framework| format!("**{framework}**"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(content, "{} {frameworks}", messages.applies_to)?;
+        writeln!(content)?;
+    }
+    // The kinds of the sources must name the sources of the rule
+    for source in extra
+        .map(|extra| extra.source_kinds.keys())
+        .into_iter()
+        .flatten()
+    {
+        if !meta
+            .sources
+            .iter()
+            .any(|known| known.to_namespaced_rule_name() == *source)
+        {
+            warnings.push(Warning::new(
+                WarningKind::UnknownSource,
+                format!("`sourceKinds` in `codegen/extra-metadata.json` sets the kind of `{source}`, which isn't a source of the rule"),
+            ));
+        }
+    }
     if !meta.sources.is_empty() {
-        writeln!(content, "Sources: ")?;
+        writeln!(content, "{} ", messages.sources)?;
 
         for source in meta.sources {
             let rule_name = source.to_namespaced_rule_name();
             let source_rule_url = source.to_rule_url();
-            match meta.source_kind.as_ref().copied().unwrap_or_default() {
+            match rule_source_kind(meta, extra, source) {
                 RuleSourceKind::Inspired => {
-                    write!(content, "- Inspired from: ")?;
+                    write!(content, "- {} ", messages.inspired_from)?;
                 }
                 RuleSourceKind::SameLogic => {
-                    write!(content, "- Same as: ")?;
+                    write!(content, "- {} ", messages.same_as)?;
                 }
             };
             writeln!(
@@ -435,114 +2180,790 @@ fn generate_rule(payload: GenRule) -> Result<Vec<Event<'static>>> {
             )?;
         }
         writeln!(content)?;
+        if meta.sources.iter().any(|source| {
+            matches!(
+                rule_source_kind(meta, extra, source),
+                RuleSourceKind::Inspired
+            )
+        }) {
+            writeln!(content, "{}", messages.source_kinds_explanation)?;
+            writeln!(content)?;
+        }
+    }
+
+    let supersedes = extra.map_or(&[][..], |extra| extra.supersedes.as_slice());
+    for (label, rules) in [
+        (
+            messages.supersedes,
+            supersedes.iter().map(String::as_str).collect::<Vec<_>>(),
+        ),
+        (messages.superseded_by, superseded_by.to_vec()),
+    ] {
+        if rules.is_empty() {
+            continue;
+        }
+        writeln!(content, "{label} ")?;
+        for related_rule in rules {
+            writeln!(
+                content,
+                "- [`{related_rule}`](/linter/rules/{})",
+                Case::Kebab.convert(related_rule)
+            )?;
+        }
+        writeln!(content)?;
+    }
+
+    let changes = extra.map_or(&[][..], |extra| extra.changes.as_slice());
+    if !changes.is_empty() {
+        let mut changes = changes
+            .iter()
+            .map(|change| {
+                let version = Version::parse(&change.version).with_context(|| {
+                    format!(
+                        "the version `{}` of a change of the rule isn't a valid semver version",
+                        change.version
+                    )
+                })?;
+                Ok((version, change.note.as_str()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // The most recent changes come first
+        changes.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+        writeln!(content, "{} ", messages.changes)?;
+        for (version, note) in changes {
+            writeln!(content, "- **v{version}**: {note}")?;
+        }
+        writeln!(content)?;
+    }
+
+    if group == "performance" {
+        match extra.and_then(|extra| extra.performance_impact.as_deref()) {
+            Some(performance_impact) => {
+                config.renderer.open_aside(
+                    &mut content,
+                    AsideType::Note,
+                    Some(messages.performance_impact),
+                )?;
+                writeln!(content, "{}", performance_impact.trim_end())?;
+                config.renderer.close_aside(&mut content)?;
+                writeln!(content)?;
+            }
+            None => {
+                warnings.push(Warning::new(
+                    WarningKind::MissingPerformanceImpact,
+                    "the performance rule doesn't have a `performanceImpact` in `codegen/extra-metadata.json`",
+                ));
+            }
+        }
     }
 
-    let summary = parse_documentation(
+    // The documentation is parsed into its own buffer, because the message of
+    // its first invalid example is rendered before it
+    let mut docs = Vec::new();
+    let documentation = parse_documentation(
         group,
         rule,
         meta.docs,
-        &mut content,
+        &mut docs,
         !matches!(meta.fix_kind, None),
+        frameworks,
+        languages,
+        snippets,
+        config,
     )?;
 
-    writeln!(content, "## Related links")?;
+    if let Some(message) = &documentation.representative_message {
+        writeln!(content, "{}", messages.diagnostic_example)?;
+        writeln!(content)?;
+        writeln!(content, "```text")?;
+        writeln!(content, "{message}")?;
+        writeln!(content, "```")?;
+        writeln!(content)?;
+    }
+
+    content.extend(docs);
+    // Whatever the documentation ends with, a code block, a list or a
+    // paragraph, the sections appended to it are preceded by one blank line
+    while content.ends_with(b"\n") {
+        content.pop();
+    }
+    writeln!(content)?;
+    writeln!(content)?;
+
+    // Long lists of values are easier to read as tables than as prose
+    let enum_options = options_schema
+        .rule_options(group, rule)
+        .map(|options| options_schema.enum_properties(options))
+        .unwrap_or_default();
+    if !enum_options.is_empty() {
+        writeln!(content, "## {}", messages.option_values)?;
+        writeln!(content)?;
+        for (option, values) in enum_options {
+            writeln!(content, "### `{option}`")?;
+            writeln!(content)?;
+            writeln!(content, "| {} | {} |", messages.value, messages.description)?;
+            writeln!(content, "| --- | --- |")?;
+            for EnumValue { value, description } in values {
+                let description = description
+                    .map(|description| escape_table_cell(&description.replace('\n', " ")))
+                    .unwrap_or_default();
+                writeln!(content, "| `{value}` | {description} |")?;
+            }
+            writeln!(content)?;
+        }
+    }
+
+    let see_also = see_also_rules(meta.docs);
+    if !see_also.is_empty() {
+        writeln!(content, "## {}", messages.see_also)?;
+        writeln!(content)?;
+        writeln!(content, "<ul class=\"see-also\">")?;
+        for rule in see_also {
+            writeln!(
+                content,
+                "<li><a href=\"/linter/rules/{}\"><code>{rule}</code></a></li>",
+                Case::Kebab.convert(&rule)
+            )?;
+        }
+        writeln!(content, "</ul>")?;
+        writeln!(content)?;
+    }
+
+    writeln!(content, "## {}", messages.related_links)?;
     writeln!(content)?;
-    writeln!(content, "- [Disable a rule](/linter/#disable-a-lint-rule)")?;
-    writeln!(content, "- [Rule options](/linter/#rule-options)")?;
+    writeln!(
+        content,
+        "- [{}](/linter/#disable-a-lint-rule)",
+        messages.disable_rule
+    )?;
+    writeln!(
+        content,
+        "- [{}](/linter/#rule-options)",
+        messages.rule_options
+    )?;
+    if let Some(url) = options_schema
+        .rule_options_definition(group, rule)
+        .and_then(|definition| options_schema_url(VERSION, definition))
+    {
+        writeln!(content, "- [{}]({url})", messages.options_schema)?;
+    }
 
-    let dashed_rule = Case::Kebab.convert(rule);
-    fs::write(root.join(format!("{dashed_rule}.md")), content)?;
+    let mut summary = Vec::new();
+    write_html(&mut summary, documentation.summary.into_iter())?;
+
+    Ok(RulePage {
+        content: String::from_utf8(content)?,
+        summary: String::from_utf8(summary)?,
+        ignore_reasons: documentation.ignore_reasons,
+        short_example: documentation.canonical_example.and_then(short_example),
+        analyzed_example_count: documentation.analyzed_example_count,
+        warnings: warnings.into_iter().chain(documentation.warnings).collect(),
+    })
+}
 
-    Ok(summary)
+/// What [parse_documentation] extracted from the documentation of a rule
+struct Documentation<'a> {
+    /// Parser events for the first paragraph of documentation in the resulting
+    /// content, used as a short summary of what the rule does in the rules page
+    summary: Vec<Event<'a>>,
+    /// The message of the diagnostic emitted by the first example that
+    /// expects a diagnostic
+    representative_message: Option<String>,
+    /// The reasons given by the examples marked with `ignore=<reason>`
+    ignore_reasons: Vec<String>,
+    /// The code of the first example that expects a diagnostic
+    canonical_example: Option<String>,
+    /// The number of examples that are analyzed, the ignored and `parse_only`
+    /// ones excluded
+    analyzed_example_count: usize,
+    /// The code blocks of the documentation, in document order
+    examples: Vec<RuleExample>,
+    /// The problems of the documentation that don't prevent the generation
+    warnings: Vec<Warning>,
 }
 
-/// Parse the documentation fragment for a lint rule (in markdown) and generates
-/// the content for the corresponding documentation page
-fn parse_documentation(
+/// A code block of the documentation of a rule, as listed in the metadata of
+/// the rules
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RuleExample {
+    /// The anchor of the example in the page of the rule, e.g. `example-1`
+    anchor: String,
+    /// The language the code block is rendered with
+    language: String,
+    /// The name of the rule options the example is analyzed with
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<String>,
+    expect_diagnostic: bool,
+    /// The diagnostics emitted for the example, rendered as text
+    diagnostics: String,
+}
+
+/// Returns the code blocks of the documentation of a rule, with the
+/// diagnostics of the examples rendered as text
+pub(crate) fn rule_examples(
     group: &'static str,
     rule: &'static str,
     docs: &'static str,
-    content: &mut Vec<u8>,
     has_fix_kind: bool,
-) -> Result<Vec<Event<'static>>> {
-    let parser = Parser::new(docs);
+    frameworks: &[Framework],
+    language: &str,
+) -> Result<Vec<RuleExample>> {
+    let config = DocsGenConfig {
+        diagnostic_backend: DiagnosticBackend::Text,
+        ..DocsGenConfig::default()
+    };
+    let documentation = parse_documentation(
+        group,
+        rule,
+        docs,
+        &mut Vec::new(),
+        has_fix_kind,
+        frameworks,
+        &[language],
+        None,
+        &config,
+    )
+    .with_context(|| format!("failed to analyze the examples of {rule}"))?;
 
-    // Parser events for the first paragraph of documentation in the resulting
-    // content, used as a short summary of what the rule does in the rules page
-    let mut summary = Vec::new();
-    let mut is_summary = false;
+    Ok(documentation.examples)
+}
 
-    // Tracks the content of the current code block if it's using a
-    // language supported for analysis
-    let mut language = None;
-    let mut list_order = None;
-    let mut list_indentation = 0;
+/// Whether the rules registered for the language can analyze the code blocks
+/// of the type. The Markdown blocks are checked through their code blocks.
+fn runs_on(block_type: &BlockType, language: &str) -> bool {
+    match block_type {
+        BlockType::Code(source) => source
+            .language
+            .rule_languages
+            .iter()
+            .any(|rule_language| rule_language.name == language),
+        BlockType::Markdown | BlockType::Foreign(_) => true,
+    }
+}
 
-    // Tracks the type and metadata of the link
-    let mut start_link_tag: Option<Tag> = None;
+/// Writes the language of a code block of the page, without the
+/// lintdoc-specific attributes
+fn write_code_block_language(content: &mut Vec<u8>, block_type: &BlockType) -> io::Result<()> {
+    match block_type {
+        BlockType::Code(source) => write!(content, "{}", (source.language.label)(source))?,
+        BlockType::Markdown => write!(content, "md")?,
+        BlockType::Foreign(lang) => write!(content, "{}", lang)?,
+    }
 
-    for event in parser {
-        if is_summary {
-            if matches!(event, Event::End(TagEnd::Paragraph)) {
-                is_summary = false;
-            } else {
-                summary.push(event.clone());
-            }
-        }
+    Ok(())
+}
+
+/// Returns the source type of the JavaScript code blocks marked with the token
+fn js_file_source(token: &str) -> JsFileSource {
+    match token {
+        "cjs" => JsFileSource::js_module().with_module_kind(ModuleKind::Script),
+        "ts" => JsFileSource::ts(),
+        "tsx" => JsFileSource::tsx(),
+        "svelte" => JsFileSource::svelte(),
+        "astro" => JsFileSource::astro(),
+        "vue" => JsFileSource::vue(),
+        _ => JsFileSource::jsx(),
+    }
+}
+
+/// Returns the source type a JavaScript code block is analyzed with
+fn js_source_type(source: &CodeSource) -> JsFileSource {
+    let source_type = js_file_source(source.token);
+    match source.module_kind {
+        Some(module_kind) => source_type.with_module_kind(module_kind),
+        None => source_type,
+    }
+}
+
+/// The language of a JavaScript code block on the page
+fn js_label(source: &CodeSource) -> String {
+    let source_type = js_source_type(source);
+    match source_type.as_embedding_kind() {
+        EmbeddingKind::Astro => "astro".to_string(),
+        EmbeddingKind::Svelte => "svelte".to_string(),
+        EmbeddingKind::Vue => "vue".to_string(),
+        _ => {
+            let label = match source_type.language() {
+                // Scripts are labelled as CommonJS, like the `cjs` blocks
+                Language::JavaScript
+                    if source_type.module_kind().is_script() && !source_type.variant().is_jsx() =>
+                {
+                    return "cjs".to_string();
+                }
+                Language::JavaScript => "js",
+                Language::TypeScript { .. } => "ts",
+            };
+            if source_type.variant().is_jsx() {
+                format!("{label}x")
+            } else {
+                label.to_string()
+            }
+        }
+    }
+}
+
+/// The heading of the sections that explain why the code reported by a rule
+/// is problematic, rendered in the aside set by `--rationale-aside`
+const RATIONALE_HEADING: &str = "Why is this bad?";
+
+/// The heading of the section that lists the related rules with `rule:` links,
+/// rendered as a structured block at the end of the page
+const SEE_ALSO_HEADING: &str = "See also";
+
+/// Returns the rules referenced by the `rule:` links of the `See also`
+/// section of the documentation
+pub(crate) fn see_also_rules(docs: &str) -> Vec<String> {
+    let mut rules = Vec::new();
+    let mut heading_text: Option<String> = None;
+    let mut section_level = None;
+    for event in Parser::new(docs) {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                if section_level.is_some_and(|section_level| level as usize <= section_level) {
+                    section_level = None;
+                }
+                heading_text = Some(String::new());
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                if heading_text.take().as_deref().map(str::trim) == Some(SEE_ALSO_HEADING) {
+                    section_level = Some(level as usize);
+                }
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some(heading_text) = &mut heading_text {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::Start(Tag::Link { dest_url, .. }) if section_level.is_some() => {
+                if let Some(rule) = dest_url.strip_prefix("rule:") {
+                    rules.push(rule.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    rules
+}
+
+/// Parse the documentation fragment for a lint rule (in markdown) and generates
+/// the content for the corresponding documentation page
+fn parse_documentation<'a>(
+    group: &'static str,
+    rule: &'static str,
+    docs: &'a str,
+    content: &mut Vec<u8>,
+    has_fix_kind: bool,
+    frameworks: &[Framework],
+    languages: &[&str],
+    mut snippets: Option<&mut SnippetsCache>,
+    config: &DocsGenConfig,
+) -> Result<Documentation<'a>> {
+    validate_formatting_markers(rule, docs)?;
+    let parser = Parser::new(docs);
+
+    let mut summary = Vec::new();
+    let mut is_summary = false;
+    let mut representative_message = None;
+    let mut canonical_example = None;
+    let mut warnings = Vec::new();
+    // Whether the documentation has analyzed examples that expect a
+    // diagnostic, and that don't
+    let mut has_invalid_examples = false;
+    let mut has_valid_examples = false;
+    let mut ignore_reasons = Vec::new();
+    // The number of code blocks seen so far, used to give each of them an
+    // anchor (`#example-1`, `#example-2`, ...) in document order
+    let mut example_count = 0;
+    let mut analyzed_example_count = 0;
+    let mut examples = Vec::new();
+    // The rule options declared by the `options=<name>` code blocks, by name
+    let mut named_options = BTreeMap::new();
+
+    // Tracks the content of the current code block if it's using a
+    // language supported for analysis
+    let mut language = None;
+    // The info string of the current code block, part of the key of its
+    // analysis in the cache
+    let mut info_string = String::new();
+    // Whether the current code block is an indented one, which isn't analyzed
+    let mut in_indented_block = false;
+    let mut lists = Lists::default();
+
+    // Tracks the type and metadata of the link
+    let mut start_link_tag: Option<Tag> = None;
+
+    // Tracks the text of the current heading, and the anchors of the page
+    // that links to the same page (`#options`) are checked against
+    let mut heading_text: Option<String> = None;
+    let messages = config.locale.messages();
+    let mut anchors = vec![heading_anchor(messages.related_links, &[])];
+    let mut same_page_links = Vec::new();
+
+    // The heading of a `Why is this bad?` section is replaced by an aside,
+    // which is closed by the next heading of the same or a higher level
+    let mut heading_start = 0;
+    let mut rationale_level = None;
+    // The `See also` section is dropped, it's rendered by [generate_rule]
+    let mut see_also_section = None;
+    // The level of the previous heading. The documentation is rendered under
+    // the title of the page, so its first heading is expected to be a `##`
+    let mut previous_heading_level = None;
+
+    for event in parser {
+        if is_summary {
+            if matches!(event, Event::End(TagEnd::Paragraph)) {
+                is_summary = false;
+            } else {
+                summary.push(event.clone());
+            }
+        }
 
         match event {
             // CodeBlock-specific handling
             Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(meta))) => {
                 // Track the content of code blocks to pass them through the analyzer
-                let test = CodeBlockTest::from_str(meta.as_ref())?;
+                let mut test = CodeBlockTest::parse(meta.as_ref(), config.doc_languages)?;
+                // Examples are analyzed in the context of the main framework
+                // of the rule, unless they declare their own
+                if test.framework.is_none() {
+                    test.framework = frameworks.first().copied();
+                }
+                if let Some(reason) = &test.ignore_reason {
+                    ignore_reasons.push(reason.clone());
+                }
+                for attribute in &test.unknown_attributes {
+                    warnings.push(Warning::new(
+                        WarningKind::UnknownAttribute,
+                        format!("the code block `{meta}` of {rule} has the unknown attribute `{attribute}`, the block is still analyzed"),
+                    ));
+                }
+                // An example in a language the rule doesn't run on would
+                // fail later without any diagnostic
+                let analyzed_type = test.analyzed_as.as_ref().unwrap_or(&test.block_type);
+                if !test.ignore
+                    && test.options_name.is_none()
+                    && !languages.is_empty()
+                    && !languages
+                        .iter()
+                        .any(|language| runs_on(analyzed_type, language))
+                {
+                    bail!(
+                        "the example `{meta}` of {rule} can't be analyzed, the rule only runs on the languages {}",
+                        languages.join(", ")
+                    );
+                }
+                if let Some(reason) = &test.parse_only_reason {
+                    ensure!(
+                        !test.expect_diagnostic,
+                        "the `parse_only` code blocks of {rule} can't expect a diagnostic"
+                    );
+                    ignore_reasons.push(format!("not analyzed: {reason}"));
+                }
+                if test.before_after || test.output {
+                    ensure!(
+                        has_fix_kind && test.expect_diagnostic,
+                        "the `before_after` and `output` code blocks of {rule} must expect a diagnostic of a rule with a `fix_kind`"
+                    );
+                }
+
+                example_count += 1;
+                anchors.push(format!("example-{example_count}"));
+                writeln!(content, "<a id=\"example-{example_count}\"></a>")?;
+                writeln!(content)?;
+                if test.label {
+                    let label = if test.expect_diagnostic {
+                        messages.invalid
+                    } else {
+                        messages.valid
+                    };
+                    writeln!(content, "**{label}**")?;
+                    writeln!(content)?;
+                }
+                if test.before_after {
+                    writeln!(content, "<div class=\"fix-comparison\">")?;
+                    writeln!(content)?;
+                    writeln!(content, "<div>")?;
+                    writeln!(content)?;
+                }
 
                 // Erase the lintdoc-specific attributes in the output by
                 // re-generating the language ID from the source type
                 write!(content, "```")?;
                 if !meta.is_empty() {
-                    match test.block_type {
-                        BlockType::Js(source_type) => match source_type.as_embedding_kind() {
-                            EmbeddingKind::Astro => write!(content, "astro")?,
-                            EmbeddingKind::Svelte => write!(content, "svelte")?,
-                            EmbeddingKind::Vue => write!(content, "vue")?,
-                            _ => {
-                                match source_type.language() {
-                                    Language::JavaScript => write!(content, "js")?,
-                                    Language::TypeScript { .. } => write!(content, "ts")?,
-                                };
-                                if source_type.variant().is_jsx() {
-                                    write!(content, "x")?;
-                                }
-                            }
-                        },
-                        BlockType::Json => write!(content, "json")?,
-                        BlockType::Css => write!(content, "css")?,
-                        BlockType::Foreign(ref lang) => write!(content, "{}", lang)?,
+                    // The rendered label must describe the same code as the
+                    // attributes it replaces
+                    let mut label = Vec::new();
+                    write_code_block_language(&mut label, &test.block_type)?;
+                    let label = String::from_utf8(label)?;
+                    if CodeBlockTest::parse(&label, config.doc_languages)?.block_type
+                        != test.block_type
+                    {
+                        warnings.push(Warning::new(
+                            WarningKind::LossyLanguageLabel,
+                            format!("the code block `{meta}` is rendered with the label `{label}`, which describes another language or module kind"),
+                        ));
+                    }
+                    write_code_block_language(content, &test.block_type)?;
+                    if let Some(file) = &test.file {
+                        write!(content, " title=\"{}\"", escape_fence_title(file))?;
+                    } else if config.default_file_titles
+                        && test.options_name.is_none()
+                        && !matches!(test.block_type, BlockType::Foreign(_))
+                    {
+                        // The label of the block is the extension of its file
+                        write!(content, " title=\"index.{label}\"")?;
                     }
                 }
                 writeln!(content)?;
 
+                info_string = meta.to_string();
                 language = Some((test, String::new()));
             }
 
+            // Indented code blocks can't declare a language, they're
+            // rendered as fenced blocks without language and aren't analyzed
+            Event::Start(Tag::CodeBlock(CodeBlockKind::Indented)) => {
+                in_indented_block = true;
+                writeln!(content, "```")?;
+            }
+
             Event::End(TagEnd::CodeBlock) => {
+                in_indented_block = false;
                 writeln!(content, "```")?;
                 writeln!(content)?;
 
-                if let Some((test, block)) = language.take() {
-                    if test.expect_diagnostic {
-                        write!(
-                            content,
-                            "<pre class=\"language-text\"><code class=\"language-text\">"
-                        )?;
+                if let Some((mut test, block)) = language.take() {
+                    // The options declared by the block are used by the
+                    // following examples, the block itself isn't analyzed
+                    if let Some(name) = test.options_name.take() {
+                        named_options.insert(name, block);
+                        continue;
+                    }
+
+                    let mut configurations = Vec::new();
+                    for name in &test.use_options {
+                        let Some(options) = named_options.get(name) else {
+                            bail!("the example of {rule} uses the options `{name}`, which aren't declared by a previous `options={name}` code block");
+                        };
+                        configurations.push((name.as_str(), options.as_str()));
                     }
+                    // An example with several options is analyzed once per
+                    // options, and the result of each of them is rendered
+                    let is_conditional = configurations.len() > 1;
+                    ensure!(
+                        !is_conditional || !(test.before_after || test.output),
+                        "the `before_after` and `output` code blocks of {rule} can't use several options"
+                    );
+                    let configurations = if configurations.is_empty() {
+                        vec![(None, test)]
+                    } else {
+                        configurations
+                            .into_iter()
+                            .map(|(name, options)| {
+                                let mut configuration = test.clone();
+                                configuration.options = Some(options.to_string());
+                                configuration.expect_diagnostic = test.expect_diagnostic
+                                    || test.expect_diagnostic_with.iter().any(|with| with == name);
+                                (Some(name.to_string()), configuration)
+                            })
+                            .collect()
+                    };
+
+                    for (name, test) in configurations {
+                        if let (Some(name), true) = (&name, is_conditional) {
+                            writeln!(
+                                content,
+                                "{}",
+                                fill(messages.with_options, &[("name", name.as_str())])
+                            )?;
+                            writeln!(content)?;
+                        }
+
+                        // The diagnostics are written after the fixed code of
+                        // `before_after` blocks
+                        let mut diagnostics = Vec::new();
+                        if test.expect_diagnostic {
+                            config
+                                .diagnostic_backend
+                                .open_block(&mut diagnostics, &config.diagnostic_class)?;
+                        }
+                        let opened = diagnostics.len();
+
+                        let outcome = match snippets.as_deref_mut() {
+                            Some(snippets) => {
+                                let key = snippet_cache_key(
+                                    group,
+                                    rule,
+                                    &info_string,
+                                    &block,
+                                    test.options.as_deref(),
+                                    has_fix_kind,
+                                    frameworks,
+                                    config,
+                                );
+
+                                match snippets.get(key) {
+                                    Some(snippet) => {
+                                        diagnostics.extend(snippet.diagnostics);
+                                        LintOutcome {
+                                            messages: snippet.messages,
+                                            fixed_code: snippet.fixed_code,
+                                            edits: snippet.edits,
+                                            replacements: snippet.replacements,
+                                        }
+                                    }
+                                    None => {
+                                        let start = diagnostics.len();
+                                        let outcome = assert_lint(
+                                            group,
+                                            rule,
+                                            &test,
+                                            &block,
+                                            &mut diagnostics,
+                                            has_fix_kind,
+                                            config,
+                                        )
+                                        .context("snapshot test failed")?;
+                                        snippets.insert(
+                                            key,
+                                            CachedSnippet {
+                                                diagnostics: diagnostics[start..].to_vec(),
+                                                messages: outcome.messages.clone(),
+                                                fixed_code: outcome.fixed_code.clone(),
+                                                edits: outcome.edits.clone(),
+                                                replacements: outcome.replacements.clone(),
+                                            },
+                                        );
+                                        outcome
+                                    }
+                                }
+                            }
+                            None => assert_lint(
+                                group,
+                                rule,
+                                &test,
+                                &block,
+                                &mut diagnostics,
+                                has_fix_kind,
+                                config,
+                            )
+                            .context("snapshot test failed")?,
+                        };
+
+                        if test.before_after {
+                            let Some(fixed_code) = &outcome.fixed_code else {
+                                bail!("the `before_after` code block of {rule} doesn't have a safe fix, code snippet:\n\n{block}");
+                            };
+                            writeln!(content, "</div>")?;
+                            writeln!(content)?;
+                            writeln!(content, "<div>")?;
+                            writeln!(content)?;
+                            write_fixed_code_block(content, &test, fixed_code, None)?;
+                            writeln!(content, "</div>")?;
+                            writeln!(content)?;
+                            writeln!(content, "</div>")?;
+                            writeln!(content)?;
+                        }
+                        if test.output {
+                            let Some(fixed_code) = &outcome.fixed_code else {
+                                bail!("the `output` code block of {rule} doesn't have a safe fix, code snippet:\n\n{block}");
+                            };
+                            // A fix that replaces a single word is rendered inline
+                            match inline_replacement(&outcome.replacements) {
+                                Some((before, after)) => {
+                                    writeln!(
+                                        content,
+                                        "{}",
+                                        fill(
+                                            messages.inline_output,
+                                            &[("before", before), ("after", after)]
+                                        )
+                                    )?;
+                                    writeln!(content)?;
+                                }
+                                None => write_fixed_code_block(
+                                    content,
+                                    &test,
+                                    fixed_code,
+                                    Some(messages.output),
+                                )?,
+                            }
+                        }
+
+                        let mut label = Vec::new();
+                        write_code_block_language(&mut label, &test.block_type)?;
+                        examples.push(RuleExample {
+                            anchor: format!("example-{example_count}"),
+                            language: String::from_utf8(label)?,
+                            options: name,
+                            expect_diagnostic: test.expect_diagnostic,
+                            diagnostics: String::from_utf8_lossy(&diagnostics[opened..])
+                                .into_owned(),
+                        });
+
+                        if test.expect_diagnostic && representative_message.is_none() {
+                            representative_message = outcome.messages.into_iter().next();
+                        }
+                        if test.expect_diagnostic && canonical_example.is_none() {
+                            canonical_example = Some(block.clone());
+                        }
+                        if !test.ignore && test.parse_only_reason.is_none() {
+                            analyzed_example_count += 1;
+                            if test.expect_diagnostic {
+                                has_invalid_examples = true;
+                            } else {
+                                has_valid_examples = true;
+                            }
+                        }
+
+                        // The file name of the diagnostics of a multi-file
+                        // example links back to the code of the file
+                        if let (Some(file), DiagnosticBackend::Html) =
+                            (&test.file, config.diagnostic_backend)
+                        {
+                            diagnostics = link_diagnostic_file(
+                                &diagnostics,
+                                file,
+                                &format!("example-{example_count}"),
+                            );
+                        }
+
+                        // Long diagnostics are collapsed to keep the page compact
+                        let collapsed = test.expect_diagnostic
+                            && config.diagnostic_backend != DiagnosticBackend::Terminal
+                            && config.collapse_diagnostics.is_some_and(|max_lines| {
+                                diagnostics[opened..].split(|&byte| byte == b'\n').count()
+                                    > max_lines
+                            });
+                        if collapsed {
+                            writeln!(content, "<details>")?;
+                            writeln!(content, "<summary>{}</summary>", messages.full_diagnostic)?;
+                            writeln!(content)?;
+                        }
 
-                    assert_lint(group, rule, &test, &block, content, has_fix_kind)
-                        .context("snapshot test failed")?;
+                        content.extend(diagnostics);
+                        if test.expect_diagnostic {
+                            config.diagnostic_backend.close_block(content)?;
+                            writeln!(content)?;
+                        }
+                        if collapsed {
+                            writeln!(content, "</details>")?;
+                            writeln!(content)?;
+                        }
+                        if !test.expect_diagnostic && is_conditional {
+                            writeln!(content, "{}", messages.no_diagnostic)?;
+                            writeln!(content)?;
+                        }
 
-                    if test.expect_diagnostic {
-                        writeln!(content, "</code></pre>")?;
-                        writeln!(content)?;
+                        // The diagnostics only show the fix of their own
+                        // sub-block, the fixes of a multi-file example are
+                        // followed by a diff of each fixed sub-block
+                        if outcome.edits.len() > 1 {
+                            write_file_diffs(content, &outcome.edits)?;
+                        }
                     }
                 }
             }
@@ -551,15 +2972,73 @@ fn parse_documentation(
                 if let Some((_, block)) = &mut language {
                     write!(block, "{text}")?;
                 }
+                if let Some(heading_text) = &mut heading_text {
+                    heading_text.push_str(&text);
+                }
+                // Notes left for the authors shouldn't ship to the users
+                if language.is_none() && !in_indented_block {
+                    if let Some(marker) = find_unfinished_marker(&text) {
+                        warnings.push(Warning::new(
+                            WarningKind::UnfinishedMarker,
+                            format!(
+                                "the documentation contains the marker `{marker}`: {}",
+                                text.trim()
+                            ),
+                        ));
+                    }
+                }
 
                 write!(content, "{text}")?;
             }
 
             // Other markdown events are emitted as-is
             Event::Start(Tag::Heading { level, .. }) => {
+                let expected_level = previous_heading_level.map_or(2, |previous| previous + 1);
+                if level as usize > expected_level {
+                    warnings.push(Warning::new(
+                        WarningKind::SkippedHeadingLevel,
+                        format!(
+                            "the documentation has a heading of level {}, but the deepest level expected at this point is {expected_level}",
+                            level as usize
+                        ),
+                    ));
+                }
+                previous_heading_level = Some(level as usize);
+                if let Some((start, see_also_level)) = see_also_section {
+                    if level as usize <= see_also_level {
+                        content.truncate(start);
+                        see_also_section = None;
+                    }
+                }
+                if rationale_level.is_some_and(|rationale_level| level as usize <= rationale_level)
+                {
+                    rationale_level = None;
+                    config.renderer.close_aside(content)?;
+                    writeln!(content)?;
+                }
+                heading_text = Some(String::new());
+                heading_start = content.len();
                 write!(content, "{} ", "#".repeat(level as usize))?;
             }
-            Event::End(TagEnd::Heading { .. }) => {
+            Event::End(TagEnd::Heading(level)) => {
+                if let Some(heading_text) = heading_text.take() {
+                    if heading_text.trim() == RATIONALE_HEADING {
+                        content.truncate(heading_start);
+                        config.renderer.open_aside(
+                            content,
+                            config.rationale_aside,
+                            Some(messages.rationale),
+                        )?;
+                        rationale_level = Some(level as usize);
+                        continue;
+                    }
+                    if heading_text.trim() == SEE_ALSO_HEADING {
+                        see_also_section = Some((heading_start, level as usize));
+                        anchors.push(heading_anchor(&heading_text, &anchors));
+                        continue;
+                    }
+                    anchors.push(heading_anchor(&heading_text, &anchors));
+                }
                 writeln!(content)?;
                 writeln!(content)?;
             }
@@ -568,6 +3047,8 @@ fn parse_documentation(
                 if summary.is_empty() && !is_summary {
                     is_summary = true;
                 }
+                // Only the items of the loose lists are made of paragraphs
+                lists.mark_loose();
             }
             Event::End(TagEnd::Paragraph) => {
                 writeln!(content)?;
@@ -575,19 +3056,25 @@ fn parse_documentation(
             }
 
             Event::Code(text) => {
+                if let Some(heading_text) = &mut heading_text {
+                    heading_text.push_str(&text);
+                }
                 write!(content, "`{text}`")?;
             }
             Event::Start(ref link_tag @ Tag::Link { link_type, .. }) => {
                 start_link_tag = Some(link_tag.clone());
                 match link_type {
-                    LinkType::Autolink => {
+                    LinkType::Autolink | LinkType::Email => {
                         write!(content, "<")?;
                     }
-                    LinkType::Inline | LinkType::Reference | LinkType::Shortcut => {
+                    LinkType::Inline
+                    | LinkType::Reference
+                    | LinkType::Collapsed
+                    | LinkType::Shortcut => {
                         write!(content, "[")?;
                     }
                     _ => {
-                        panic!("unimplemented link type")
+                        bail!("the documentation of {rule} has a link of the unsupported type {link_type:?}")
                     }
                 }
             }
@@ -600,23 +3087,36 @@ fn parse_documentation(
                 }) = start_link_tag
                 {
                     match link_type {
-                        LinkType::Autolink => {
+                        LinkType::Autolink | LinkType::Email => {
                             write!(content, ">")?;
                         }
-                        LinkType::Inline | LinkType::Reference | LinkType::Shortcut => {
-                            write!(content, "]({dest_url}")?;
+                        LinkType::Inline
+                        | LinkType::Reference
+                        | LinkType::Collapsed
+                        | LinkType::Shortcut => {
+                            if let Some(anchor) = dest_url.strip_prefix('#') {
+                                same_page_links.push(anchor.to_string());
+                            }
+                            match dest_url.strip_prefix("rule:") {
+                                Some(rule) => write!(
+                                    content,
+                                    "](/linter/rules/{}",
+                                    Case::Kebab.convert(rule)
+                                )?,
+                                None => write!(content, "]({dest_url}")?,
+                            }
                             if !title.is_empty() {
                                 write!(content, " \"{title}\"")?;
                             }
                             write!(content, ")")?;
                         }
                         _ => {
-                            panic!("unimplemented link type")
+                            bail!("the documentation of {rule} has a link of the unsupported type {link_type:?}")
                         }
                     }
                     start_link_tag = None;
                 } else {
-                    panic!("missing start link tag");
+                    bail!("the documentation of {rule} closes a link that was never opened");
                 }
             }
 
@@ -629,33 +3129,49 @@ fn parse_documentation(
             }
 
             Event::Start(Tag::List(num)) => {
-                list_indentation += 1;
-                if let Some(num) = num {
-                    list_order = Some(num);
-                }
-                if list_indentation > 1 {
+                lists.open(num);
+                // A nested list starts on the line after the text of its item
+                if lists.depth() > 1 && !content.ends_with(b"\n") {
                     writeln!(content)?;
                 }
             }
 
+            // Unbalanced list events are reported instead of underflowing
+            // the depth of the lists
             Event::End(TagEnd::List(_)) => {
-                list_order = None;
-                list_indentation -= 1;
-                writeln!(content)?;
+                ensure!(
+                    lists.close(),
+                    "the documentation of {rule} closes a list that was never opened"
+                );
+                if !content.ends_with(b"\n\n") {
+                    writeln!(content)?;
+                }
             }
             Event::Start(Tag::Item) => {
-                write!(content, "{}", "  ".repeat(list_indentation - 1))?;
-                if let Some(num) = list_order {
-                    write!(content, "{num}. ")?;
-                } else {
-                    write!(content, "- ")?;
-                }
+                let Some(marker) = lists.open_item(content.len()) else {
+                    bail!("the documentation of {rule} has a list item outside of a list");
+                };
+                write!(content, "{marker}")?;
             }
 
+            // The content of the item, including its code blocks and nested
+            // lists, is indented under its marker once it's complete
             Event::End(TagEnd::Item) => {
-                list_order = list_order.map(|item| item + 1);
+                let Some((position, width, loose)) = lists.close_item() else {
+                    bail!("the documentation of {rule} closes a list item that was never opened");
+                };
+                let item = String::from_utf8(content.split_off(position))?;
+                write_list_item(content, &item, width, loose)?;
+            }
+
+            // The HTML blocks and the inline HTML are written as they are
+            Event::Start(Tag::HtmlBlock) => {}
+            Event::End(TagEnd::HtmlBlock) => {
                 writeln!(content)?;
             }
+            Event::Html(html) | Event::InlineHtml(html) => {
+                write!(content, "{html}")?;
+            }
 
             Event::Start(Tag::Strong) => {
                 write!(content, "**")?;
@@ -696,465 +3212,1808 @@ fn parse_documentation(
         }
     }
 
-    Ok(summary)
+    if let Some((start, _)) = see_also_section {
+        content.truncate(start);
+    }
+    if rationale_level.is_some() {
+        config.renderer.close_aside(content)?;
+        writeln!(content)?;
+    }
+
+    // The summary is the description of the rule in the index
+    let summary_text = summary
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect::<String>();
+    if is_uninformative_summary(&summary_text, rule) {
+        warnings.push(Warning::new(
+            WarningKind::UninformativeSummary,
+            format!(
+                "the summary `{}` doesn't describe what the rule reports",
+                summary_text.trim()
+            ),
+        ));
+    }
+
+    // Readers expect to see how to fix the code reported by the rule
+    if has_invalid_examples && !has_valid_examples && group != "nursery" {
+        warnings.push(Warning::new(
+            WarningKind::MissingValidExample,
+            "the documentation has invalid examples but no valid example",
+        ));
+    }
+
+    for link in same_page_links {
+        ensure!(
+            anchors.contains(&link),
+            "the documentation of {rule} links to `#{link}`, which isn't a heading or an example of the page"
+        );
+    }
+
+    Ok(Documentation {
+        summary,
+        representative_message,
+        ignore_reasons,
+        canonical_example,
+        analyzed_example_count,
+        examples,
+        warnings,
+    })
+}
+
+/// The minimum number of words of the summary of a rule
+const MIN_SUMMARY_WORDS: usize = 2;
+
+/// Whether the summary of a rule is shorter than [MIN_SUMMARY_WORDS], or is
+/// only the name of the rule
+fn is_uninformative_summary(summary: &str, rule: &str) -> bool {
+    let summary = summary.trim().trim_end_matches('.');
+    summary.split_whitespace().count() < MIN_SUMMARY_WORDS
+        || summary
+            .chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .eq(rule.chars().flat_map(char::to_lowercase))
+}
+
+/// Returns the URL of the definition of the options of a rule in the JSON
+/// Schema published for the given version of Biome. Development builds have
+/// no published schema.
+fn options_schema_url(version: &str, definition: &str) -> Option<String> {
+    (version != "0.0.0")
+        .then(|| format!("/schemas/{version}/schema.json#/definitions/{definition}"))
+}
+
+/// Returns the text replaced by the safe fix of an example and its
+/// replacement, when the example has a single safe code action and the action
+/// replaces a single place of a single line
+fn inline_replacement(replacements: &[(String, String)]) -> Option<(&str, &str)> {
+    let [(before, after)] = replacements else {
+        return None;
+    };
+    single_line_edit(before, after)
+}
+
+/// Returns the text replaced by a fix and its replacement, extended to whole
+/// words, when the fix replaces a single place of a single line
+fn single_line_edit<'a>(code: &'a str, fixed_code: &'a str) -> Option<(&'a str, &'a str)> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+
+    let mut prefix = code
+        .bytes()
+        .zip(fixed_code.bytes())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !code.is_char_boundary(prefix) {
+        prefix -= 1;
+    }
+    let mut suffix = code[prefix..]
+        .bytes()
+        .rev()
+        .zip(fixed_code[prefix..].bytes().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    while !code.is_char_boundary(code.len() - suffix) {
+        suffix -= 1;
+    }
+
+    let start = code[..prefix]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| !is_word(*c))
+        .map_or(0, |(index, c)| index + c.len_utf8());
+    let end = code.len() - suffix;
+    let extension = code[end..]
+        .find(|c| !is_word(c))
+        .unwrap_or(code.len() - end);
+
+    let before = &code[start..end + extension];
+    let after = &fixed_code[start..fixed_code.len() - suffix + extension];
+    let is_inline = |text: &str| !text.trim().is_empty() && !text.contains(['\n', '`']);
+    (is_inline(before) && is_inline(after)).then_some((before, after))
+}
+
+/// Writes the code of an example after applying the safe fix of the rule
+fn write_fixed_code_block(
+    content: &mut Vec<u8>,
+    test: &CodeBlockTest,
+    fixed_code: &str,
+    title: Option<&str>,
+) -> io::Result<()> {
+    write!(content, "```")?;
+    write_code_block_language(content, &test.block_type)?;
+    if let Some(title) = title {
+        write!(content, " title=\"{title}\"")?;
+    }
+    writeln!(content)?;
+    write!(content, "{fixed_code}")?;
+    if !fixed_code.ends_with('\n') {
+        writeln!(content)?;
+    }
+    writeln!(content, "```")?;
+    writeln!(content)
+}
+
+/// The lists of the documentation that are open, while it's rendered
+#[derive(Debug, Default)]
+struct Lists {
+    /// The open lists, from the outermost one
+    lists: Vec<List>,
+}
+
+/// A list of the documentation that is open
+#[derive(Debug)]
+struct List {
+    /// The number of the next item, `None` for an unordered list
+    order: Option<u64>,
+    /// Whether the items are separated by blank lines, their content is
+    /// then made of paragraphs
+    loose: bool,
+    /// The position of the open item in the content, and the width of its
+    /// marker
+    item: Option<(usize, usize)>,
+}
+
+impl Lists {
+    /// The number of nested lists
+    fn depth(&self) -> usize {
+        self.lists.len()
+    }
+
+    fn open(&mut self, start: Option<u64>) {
+        self.lists.push(List {
+            order: start,
+            loose: false,
+            item: None,
+        });
+    }
+
+    /// Closes the innermost list, returns `false` if no list is open
+    fn close(&mut self) -> bool {
+        self.lists.pop().is_some()
+    }
+
+    /// Opens an item of the innermost list at the position of the content,
+    /// returns its marker or `None` if no list is open. The marker isn't
+    /// indented, the content of the items is indented when they're closed.
+    fn open_item(&mut self, position: usize) -> Option<String> {
+        let list = self.lists.last_mut()?;
+        let marker = match list.order {
+            Some(num) => format!("{num}. "),
+            None => "- ".to_string(),
+        };
+        list.item = Some((position, marker.len()));
+        Some(marker)
+    }
+
+    /// Marks the innermost list as loose
+    fn mark_loose(&mut self) {
+        if let Some(list) = self.lists.last_mut() {
+            list.loose = true;
+        }
+    }
+
+    /// Closes the open item of the innermost list, returns its position, the
+    /// width of its marker and whether the list is loose, or `None` if no
+    /// item is open
+    fn close_item(&mut self) -> Option<(usize, usize, bool)> {
+        let list = self.lists.last_mut()?;
+        let (position, width) = list.item.take()?;
+        if let Some(num) = &mut list.order {
+            *num = num.saturating_add(1);
+        }
+        Some((position, width, list.loose))
+    }
+}
+
+/// Writes an item of a list, the lines following its marker are indented by
+/// the width of the marker so that they stay in the item. The items of a
+/// loose list are followed by a blank line.
+fn write_list_item(content: &mut Vec<u8>, item: &str, width: usize, loose: bool) -> Result<()> {
+    let indentation = " ".repeat(width);
+    for (index, line) in item.trim_end_matches('\n').lines().enumerate() {
+        if index > 0 {
+            writeln!(content)?;
+            if !line.is_empty() {
+                write!(content, "{indentation}")?;
+            }
+        }
+        write!(content, "{line}")?;
+    }
+    writeln!(content)?;
+    if loose {
+        writeln!(content)?;
+    }
+    Ok(())
+}
+
+/// Returns the first `TODO`, `FIXME` or `XXX` word of the text
+fn find_unfinished_marker(text: &str) -> Option<&'static str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .find_map(|word| match word {
+            "TODO" => Some("TODO"),
+            "FIXME" => Some("FIXME"),
+            "XXX" => Some("XXX"),
+            _ => None,
+        })
+}
+
+/// Turns the first occurrence of the file name in the HTML of the diagnostics
+/// into a link to the anchor of the example of the file.
+///
+/// Every occurrence of the path can only wrap after its separators, so that a
+/// long path is never cut in the middle of the name of a directory or of the
+/// file.
+fn link_diagnostic_file(diagnostics: &[u8], file: &str, anchor: &str) -> Vec<u8> {
+    let diagnostics = String::from_utf8_lossy(diagnostics);
+    let file = file
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+    let wrapped_file = file.replace('/', "/<wbr>");
+    let mut parts = diagnostics.split(file.as_str());
+    let mut linked = parts.next().unwrap_or_default().to_string();
+    for (index, part) in parts.enumerate() {
+        if index == 0 {
+            linked.push_str(&format!(
+                "<a href=\"#{anchor}\" class=\"diagnostic-file\">{wrapped_file}</a>"
+            ));
+        } else {
+            linked.push_str(&wrapped_file);
+        }
+        linked.push_str(part);
+    }
+    linked.into_bytes()
+}
+
+/// Checks that the text of the documentation doesn't contain formatting
+/// markers (`*`, `_` and `~`) that Markdown left as is, because they aren't
+/// closed like in `**bold`, so that they don't leak into the generated page.
+///
+/// The markers are looked up in the source of the text, where the escaped
+/// markers (`\*`) can be told apart. An underscore inside a word, like in
+/// `snake_case`, and a marker surrounded by spaces, like in `2 * 3`, aren't
+/// formatting markers.
+fn validate_formatting_markers(rule: &str, docs: &str) -> Result<()> {
+    let is_escaped = |position: usize| {
+        docs[..position]
+            .bytes()
+            .rev()
+            .take_while(|&byte| byte == b'\\')
+            .count()
+            % 2
+            == 1
+    };
+    let is_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let is_space = |c: Option<char>| c.map_or(true, char::is_whitespace);
+
+    let mut in_code_block = false;
+    let mut in_autolink = false;
+    for (event, range) in Parser::new(docs).into_offset_iter() {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Link {
+                link_type: LinkType::Autolink | LinkType::Email,
+                ..
+            }) => in_autolink = true,
+            Event::End(TagEnd::Link) => in_autolink = false,
+            Event::Text(_) if !in_code_block && !in_autolink => {
+                let source = &docs[range.clone()];
+                for (index, marker) in source.char_indices() {
+                    if !matches!(marker, '*' | '_' | '~') || is_escaped(range.start + index) {
+                        continue;
+                    }
+                    let previous = docs[..range.start + index].chars().next_back();
+                    let next = source[index + 1..].chars().next();
+                    if marker == '_' && is_word(previous) && is_word(next) {
+                        continue;
+                    }
+                    if is_space(previous) && is_space(next) {
+                        continue;
+                    }
+                    bail!(
+                        "the documentation of {rule} has the unclosed formatting marker `{marker}` in `{}`, it must be escaped with a backslash if it isn't a marker",
+                        source.trim()
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Computes the anchor of a heading like Starlight does: the text is lowercased,
+/// punctuation is removed and spaces become hyphens. A counter is appended to
+/// the anchors that already exist on the page.
+pub(crate) fn heading_anchor(text: &str, anchors: &[String]) -> String {
+    let slug = text
+        .trim()
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            '-' | '_' => Some(c),
+            _ if c.is_alphanumeric() => Some(c),
+            _ => None,
+        })
+        .collect::<String>();
+
+    let mut anchor = slug.clone();
+    let mut count = 0;
+    while anchors.contains(&anchor) {
+        count += 1;
+        anchor = format!("{slug}-{count}");
+    }
+    anchor
+}
+
+/// The code of a code block in one of the documented languages
+#[derive(Clone)]
+struct CodeSource {
+    language: &'static DocLanguage,
+    /// The token the block is analyzed like, for example `jsx` for `js` blocks
+    token: &'static str,
+    /// The module kind forced with `esm` or `script`, when it isn't the one
+    /// of the token
+    module_kind: Option<ModuleKind>,
 }
 
+impl PartialEq for CodeSource {
+    fn eq(&self, other: &Self) -> bool {
+        self.language.name == other.language.name
+            && self.token == other.token
+            && self.module_kind == other.module_kind
+    }
+}
+
+#[derive(Clone, PartialEq)]
 enum BlockType {
-    Js(JsFileSource),
-    Json,
-    Css,
+    Code(CodeSource),
+    /// Markdown containing fenced code blocks, each of them is analyzed
+    Markdown,
     Foreign(String),
 }
 
+#[derive(Clone)]
 struct CodeBlockTest {
     block_type: BlockType,
     expect_diagnostic: bool,
     ignore: bool,
+    /// Why the block is ignored, when it's marked with `ignore=<reason>`
+    ignore_reason: Option<String>,
+    /// The path of the virtual file of the example, set with `file=<path>`.
+    /// It's used as title of the code block and in the emitted diagnostics.
+    file: Option<String>,
+    /// Whether the block is rendered next to the result of applying the safe
+    /// fix of the rule, set with `before_after`
+    before_after: bool,
+    /// Whether the block is followed by an `Output` panel with the result of
+    /// applying the safe fix of the rule, set with `output`
+    output: bool,
+    /// The framework the example is analyzed for, set with `framework=<name>`
+    framework: Option<Framework>,
+    /// The global variables defined in the environment of the example, set
+    /// with `global=<name>` once per variable, or with `env=<environment>`
+    /// for the globals of an [ENVIRONMENTS] entry
+    globals: Vec<String>,
+    /// Why the block is parsed but not analyzed by the rule, when it's marked
+    /// with `parse_only=<reason>`. Unlike `ignore`, parsing errors are reported.
+    parse_only_reason: Option<String>,
+    /// The options of the JavaScript parser, enabled with `parser=<option>`
+    parser_options: JsParserOptions,
+    /// Whether the example is labelled as valid or invalid, depending on
+    /// `expect_diagnostic`, enabled with `label`
+    label: bool,
+    /// The severity of the diagnostics of the example, set with
+    /// `severity=<severity>`. The severity of the rule by default.
+    severity: Option<Severity>,
+    /// The name of the rule options declared by the block, set with
+    /// `options=<name>`. The block contains the options as JSON.
+    options_name: Option<String>,
+    /// The names of the rule options the example is analyzed with, set with
+    /// `use_options=<name>` once per options
+    use_options: Vec<String>,
+    /// The names of the options the example is expected to emit a diagnostic
+    /// with, set with `expect_diagnostic=<name>`
+    expect_diagnostic_with: Vec<String>,
+    /// The rule options the example is analyzed with, as JSON
+    options: Option<String>,
+    /// The title of a code action the diagnostic of the example is expected
+    /// to have, set with `expect_action="<title>"`
+    expect_action: Option<String>,
+    /// The number of code actions the diagnostic of the example is expected
+    /// to have, suppressions excluded, set with `expect_fixes=<number>`
+    expect_fixes: Option<usize>,
+    /// The language the example is analyzed as, set with `as=<extension>`.
+    /// The block is still rendered with the language of [Self::block_type].
+    analyzed_as: Option<BlockType>,
+    /// The unknown tokens following a known language, reported as warnings
+    unknown_attributes: Vec<String>,
+}
+
+/// The global variables of the environments an example can declare with
+/// `env=<environment>`
+const ENVIRONMENTS: &[(&str, &[&str])] = &[
+    (
+        "browser",
+        &[
+            "window",
+            "document",
+            "navigator",
+            "location",
+            "history",
+            "localStorage",
+            "sessionStorage",
+            "fetch",
+            "alert",
+        ],
+    ),
+    (
+        "node",
+        &[
+            "process",
+            "require",
+            "module",
+            "exports",
+            "__dirname",
+            "__filename",
+            "Buffer",
+            "global",
+        ],
+    ),
+    (
+        "jest",
+        &[
+            "jest",
+            "describe",
+            "it",
+            "test",
+            "expect",
+            "beforeAll",
+            "beforeEach",
+            "afterAll",
+            "afterEach",
+        ],
+    ),
+];
+
+/// Splits the info string of a code block into its tokens, separated by
+/// commas and whitespace. The value of a token can be quoted to contain
+/// separators, e.g. `expect_action="Remove the variable"`, the quotes are
+/// removed.
+///
+/// This is based on the parsing logic for code block languages in `rustdoc`:
+/// https://github.com/rust-lang/rust/blob/6ac8adad1f7d733b5b97d1df4e7f96e73a46db42/src/librustdoc/html/markdown.rs#L873
+fn info_string_tokens(input: &str) -> Result<Vec<String>> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut in_quotes = false;
+    for c in input.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' | ' ' | '\t' if !in_quotes => {
+                if !token.is_empty() {
+                    tokens.push(mem::take(&mut token));
+                }
+            }
+            _ => token.push(c),
+        }
+    }
+    ensure!(!in_quotes, "the code block `{input}` has an unclosed quote");
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
 }
 
+/// The options of the JavaScript parser that `parser=<option>` can enable
+const PARSER_OPTIONS: &[&str] = &["class_parameter_decorators"];
+
 impl FromStr for CodeBlockTest {
     type Err = anyhow::Error;
 
     fn from_str(input: &str) -> Result<Self> {
-        // This is based on the parsing logic for code block languages in `rustdoc`:
-        // https://github.com/rust-lang/rust/blob/6ac8adad1f7d733b5b97d1df4e7f96e73a46db42/src/librustdoc/html/markdown.rs#L873
-        let tokens = input
-            .split(|c| c == ',' || c == ' ' || c == '\t')
-            .map(str::trim)
-            .filter(|token| !token.is_empty());
+        Self::parse(input, DOC_LANGUAGES)
+    }
+}
+
+impl CodeBlockTest {
+    /// Parses the info string of a code block, whose language is one of
+    /// `languages`, Markdown or a foreign language
+    fn parse(input: &str, languages: &'static [DocLanguage]) -> Result<Self> {
+        let tokens = info_string_tokens(input)?;
 
         let mut test = CodeBlockTest {
             block_type: BlockType::Foreign("".into()),
             expect_diagnostic: false,
             ignore: false,
+            ignore_reason: None,
+            file: None,
+            before_after: false,
+            output: false,
+            framework: None,
+            globals: Vec::new(),
+            parse_only_reason: None,
+            parser_options: JsParserOptions::default(),
+            label: false,
+            severity: None,
+            options_name: None,
+            use_options: Vec::new(),
+            expect_diagnostic_with: Vec::new(),
+            options: None,
+            expect_action: None,
+            expect_fixes: None,
+            analyzed_as: None,
+            unknown_attributes: Vec::new(),
         };
-
-        for token in tokens {
+        // Applied once the language is known, whatever the order of the tokens
+        let mut module_kind = None;
+
+        for token in &tokens {
+            let token = token.as_str();
+            if let Some(source) = languages
+                .iter()
+                .find_map(|language| language.code_source(token))
+            {
+                test.block_type = BlockType::Code(source);
+                continue;
+            }
             match token {
-                // Determine the language, using the same list of extensions as `compute_source_type_from_path_or_extension`
-                "cjs" => {
-                    test.block_type = BlockType::Js(
-                        JsFileSource::js_module().with_module_kind(ModuleKind::Script),
-                    );
+                "md" | "markdown" => {
+                    test.block_type = BlockType::Markdown;
                 }
-                "js" | "mjs" | "jsx" => {
-                    test.block_type = BlockType::Js(JsFileSource::jsx());
-                }
-                "ts" | "mts" | "cts" => {
-                    test.block_type = BlockType::Js(JsFileSource::ts());
+                // Other attributes
+                "expect_diagnostic" => {
+                    test.expect_diagnostic = true;
                 }
-                "tsx" => {
-                    test.block_type = BlockType::Js(JsFileSource::tsx());
+                "ignore" => {
+                    test.ignore = true;
                 }
-                "svelte" => {
-                    test.block_type = BlockType::Js(JsFileSource::svelte());
+                "before_after" => {
+                    test.before_after = true;
                 }
-                "astro" => {
-                    test.block_type = BlockType::Js(JsFileSource::astro());
+                "output" => {
+                    test.output = true;
                 }
-                "vue" => {
-                    test.block_type = BlockType::Js(JsFileSource::vue());
+                "label" => {
+                    test.label = true;
                 }
-                "json" => {
-                    test.block_type = BlockType::Json;
+                // Force the module kind of the code of the languages with modules
+                "esm" => {
+                    module_kind = Some(ModuleKind::Module);
                 }
-                "css" => {
-                    test.block_type = BlockType::Css;
+                "script" => {
+                    module_kind = Some(ModuleKind::Script);
                 }
-                // Other attributes
-                "expect_diagnostic" => {
-                    test.expect_diagnostic = true;
-                }
-                "ignore" => {
+                _ if token.starts_with("ignore=") => {
+                    let reason = &token["ignore=".len()..];
+                    ensure!(
+                        !reason.is_empty(),
+                        "the `ignore=` attribute must give the reason the code block is ignored"
+                    );
                     test.ignore = true;
+                    test.ignore_reason = Some(reason.to_string());
+                }
+                _ if token.starts_with("file=") => {
+                    test.file = Some(token["file=".len()..].to_string());
+                }
+                _ if token.starts_with("parse_only=") => {
+                    test.parse_only_reason = Some(token["parse_only=".len()..].to_string());
+                }
+                _ if token.starts_with("parser=") => match &token["parser=".len()..] {
+                    "class_parameter_decorators" => {
+                        test.parser_options =
+                            test.parser_options.with_parse_class_parameter_decorators();
+                    }
+                    option => bail!(
+                        "unknown parser option `{option}`, the supported options are: {}",
+                        PARSER_OPTIONS
+                            .iter()
+                            .map(|option| format!("`{option}`"))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                },
+                _ if token.starts_with("severity=") => {
+                    test.severity = Some(match &token["severity=".len()..] {
+                        "hint" => Severity::Hint,
+                        "info" => Severity::Information,
+                        "warn" => Severity::Warning,
+                        "error" => Severity::Error,
+                        severity => bail!(
+                            "unknown severity `{severity}`, expected `hint`, `info`, `warn` or `error`"
+                        ),
+                    });
+                }
+                _ if token.starts_with("as=") => {
+                    let extension = &token["as=".len()..];
+                    let block_type = CodeBlockTest::parse(extension, languages)?.block_type;
+                    ensure!(
+                        !matches!(block_type, BlockType::Foreign(_)),
+                        "unknown extension `{extension}` in `as={extension}`"
+                    );
+                    test.analyzed_as = Some(block_type);
+                }
+                _ if token.starts_with("expect_fixes=") => {
+                    let count = &token["expect_fixes=".len()..];
+                    test.expect_fixes = Some(count.parse().with_context(|| {
+                        format!("the number of fixes `{count}` isn't a number")
+                    })?);
+                }
+                _ if token.starts_with("expect_action=") => {
+                    test.expect_action = Some(token["expect_action=".len()..].to_string());
+                }
+                _ if token.starts_with("options=") => {
+                    test.options_name = Some(token["options=".len()..].to_string());
+                }
+                _ if token.starts_with("use_options=") => {
+                    test.use_options
+                        .push(token["use_options=".len()..].to_string());
+                }
+                _ if token.starts_with("expect_diagnostic=") => {
+                    test.expect_diagnostic_with
+                        .push(token["expect_diagnostic=".len()..].to_string());
+                }
+                _ if token.starts_with("global=") => {
+                    test.globals.push(token["global=".len()..].to_string());
+                }
+                _ if token.starts_with("env=") => {
+                    let environment = &token["env=".len()..];
+                    let Some((_, globals)) =
+                        ENVIRONMENTS.iter().find(|(name, _)| *name == environment)
+                    else {
+                        bail!(
+                            "unknown environment `{environment}`, expected `browser`, `node` or `jest`"
+                        );
+                    };
+                    test.globals
+                        .extend(globals.iter().map(|global| global.to_string()));
+                }
+                _ if token.starts_with("framework=") => {
+                    test.framework = Some(token["framework=".len()..].parse()?);
                 }
                 // A catch-all to regard unknown tokens as foreign languages,
                 // and do not run tests on these code blocks.
                 _ => {
-                    test.block_type = BlockType::Foreign(token.into());
-                    test.ignore = true;
+                    // After a known language, an unknown token is more likely
+                    // a misspelled attribute, which shouldn't silently turn
+                    // the analysis of the block off
+                    if matches!(test.block_type, BlockType::Foreign(_)) {
+                        test.block_type = BlockType::Foreign(token.into());
+                        test.ignore = true;
+                    } else {
+                        test.unknown_attributes.push(token.to_string());
+                    }
                 }
             }
         }
 
+        if let Some(module_kind) = module_kind {
+            let source = match &mut test.block_type {
+                BlockType::Code(source) => Some(source),
+                _ => None,
+            };
+            let Some((source, default_module_kind)) = source.and_then(|source| {
+                let default_module_kind = (source.language.module_kind)(source.token)?;
+                Some((source, default_module_kind))
+            }) else {
+                bail!("the `esm` and `script` attributes can only be used with the code blocks of a language with modules, like JavaScript");
+            };
+            // Without an actual change, the block is the same as the blocks
+            // of the token without attribute
+            source.module_kind = (module_kind != default_module_kind).then_some(module_kind);
+        }
+        if test.options_name.is_some() {
+            ensure!(
+                matches!(&test.block_type, BlockType::Code(source) if source.language.name == "json"),
+                "the `options` attribute can only be used with JSON code blocks"
+            );
+        }
+        // With a single options, the example is expected to emit a
+        // diagnostic or not like any other example
+        if let [name] = test.use_options.as_slice() {
+            test.expect_diagnostic |= test.expect_diagnostic_with.contains(name);
+        }
+        if test.expect_action.is_some() || test.expect_fixes.is_some() {
+            ensure!(
+                test.expect_diagnostic,
+                "the `expect_action` and `expect_fixes` attributes can only be used with `expect_diagnostic`"
+            );
+        }
+
         Ok(test)
     }
 }
 
-/// Parse and analyze the provided code block, and asserts that it emits
-/// exactly zero or one diagnostic depending on the value of `expect_diagnostic`.
-/// That diagnostic is then emitted as text into the `content` buffer
-fn assert_lint(
-    group: &'static str,
-    rule: &'static str,
-    test: &CodeBlockTest,
-    code: &str,
-    content: &mut Vec<u8>,
-    has_fix_kind: bool,
-) -> Result<()> {
-    let file = format!("{group}/{rule}.js");
+/// Checks that the list of the recommended rules contains each of the
+/// expected rules exactly once
+fn validate_recommended_rules(list: &str, expected: &BTreeSet<&str>) -> Result<()> {
+    let mut listed = BTreeSet::new();
+    for entry in list.lines().filter(|line| line.contains("<li>")) {
+        let rule = entry
+            .split_once("'>")
+            .and_then(|(_, rest)| rest.split_once("</a>"))
+            .map(|(rule, _)| rule)
+            .with_context(|| format!("malformed entry of the recommended rules: {entry}"))?;
+        ensure!(
+            listed.insert(rule),
+            "the rule {rule} is listed more than once in the recommended rules"
+        );
+    }
 
-    let mut write = HTML(content);
-    let mut diagnostic_count = 0;
+    let missing = expected.difference(&listed).collect::<Vec<_>>();
+    ensure!(
+        missing.is_empty(),
+        "the recommended rules {missing:?} are missing from the list of the recommended rules"
+    );
+    let unexpected = listed.difference(expected).collect::<Vec<_>>();
+    ensure!(
+        unexpected.is_empty(),
+        "the rules {unexpected:?} are listed in the recommended rules, but they aren't recommended"
+    );
 
-    let mut all_diagnostics = vec![];
+    Ok(())
+}
 
-    let mut write_diagnostic = |code: &str, diag: biome_diagnostics::Error| {
-        let category = diag.category().map_or("", |code| code.name());
+/// Returns the lint rules of every registry, by group
+pub(crate) fn lint_rules() -> BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>> {
+    LintRulesVisitor::visit(&DocsGenConfig::default()).groups
+}
 
-        Formatter::new(&mut write).write_markup(markup! {
-            {PrintDiagnostic::verbose(&diag)}
-        })?;
+/// Returns the group, the name and the metadata of the lint rule
+fn find_rule(rule: &str) -> Result<(&'static str, &'static str, RuleMetadata)> {
+    let Some(found) = lint_rules().into_iter().find_map(|(group, mut rules)| {
+        rules
+            .remove_entry(rule)
+            .map(|(rule, meta)| (group, rule, meta))
+    }) else {
+        bail!("the rule {rule} doesn't exist");
+    };
 
-        all_diagnostics.push(diag);
-        // Fail the test if the analysis returns more diagnostics than expected
-        if test.expect_diagnostic {
-            // Print all diagnostics to help the user
-            if all_diagnostics.len() > 1 {
-                let mut console = biome_console::EnvConsole::default();
-                for diag in all_diagnostics.iter() {
-                    console.println(
-                        biome_console::LogLevel::Error,
-                        markup! {
-                            {PrintDiagnostic::verbose(diag)}
-                        },
-                    );
-                }
-            }
+    Ok(found)
+}
 
-            ensure!(
-                diagnostic_count == 0,
-                "analysis returned multiple diagnostics, code snippet: \n\n{}",
-                code
-            );
-        } else {
-            // Print all diagnostics to help the user
-            let mut console = biome_console::EnvConsole::default();
-            for diag in all_diagnostics.iter() {
-                console.println(
-                    biome_console::LogLevel::Error,
-                    markup! {
-                        {PrintDiagnostic::verbose(diag)}
-                    },
-                );
-            }
+/// Prints the documentation of a rule to the terminal, with the diagnostics
+/// of its examples
+pub fn explain_rule(rule: &str) -> Result<()> {
+    let (group, rule, content) = rule_explanation(rule)?;
 
-            bail!(format!(
-                "analysis returned an unexpected diagnostic, code `snippet:\n\n{:?}\n\n{}",
-                category, code
-            ));
-        }
+    let mut console = biome_console::EnvConsole::default();
+    console.log(markup! {
+        <Emphasis>"lint/"{group}"/"{rule}</Emphasis>"\n"
+    });
+    io::stdout().write_all(&content)?;
 
-        diagnostic_count += 1;
-        Ok(())
+    Ok(())
+}
+
+/// Returns the group and the name of a rule, with its documentation as
+/// printed by [explain_rule]
+fn rule_explanation(rule: &str) -> Result<(&'static str, &'static str, Vec<u8>)> {
+    let (group, rule, meta) = find_rule(rule)?;
+    let extra_metadata = ExtraMetadata::load()?;
+    let frameworks = extra_metadata
+        .rule(rule)
+        .map_or(&[][..], |extra| extra.frameworks.as_slice());
+    let config = DocsGenConfig {
+        diagnostic_backend: DiagnosticBackend::Terminal,
+        renderer: Renderer::new(PlainText),
+        ..DocsGenConfig::default()
     };
-    if test.ignore {
-        return Ok(());
-    }
-    let mut rule_has_code_action = false;
-    let mut settings = WorkspaceSettings::default();
-    let key = settings.insert_project(PathBuf::new());
-    settings.register_current_project(key);
-    match test.block_type {
-        BlockType::Js(source_type) => {
-            // Temporary support for astro, svelte and vue code blocks
-            let (code, source_type) = match source_type.as_embedding_kind() {
-                EmbeddingKind::Astro => (
-                    biome_service::file_handlers::AstroFileHandler::input(code),
-                    JsFileSource::ts(),
-                ),
-                EmbeddingKind::Svelte => (
-                    biome_service::file_handlers::SvelteFileHandler::input(code),
-                    biome_service::file_handlers::SvelteFileHandler::file_source(code),
-                ),
-                EmbeddingKind::Vue => (
-                    biome_service::file_handlers::VueFileHandler::input(code),
-                    biome_service::file_handlers::VueFileHandler::file_source(code),
-                ),
-                _ => (code, source_type),
-            };
 
-            let parse = biome_js_parser::parse(code, source_type, JsParserOptions::default());
+    let mut content = Vec::new();
+    parse_documentation(
+        group,
+        rule,
+        meta.docs,
+        &mut content,
+        meta.fix_kind.is_some(),
+        frameworks,
+        &[meta.language],
+        None,
+        &config,
+    )?;
 
-            if parse.has_errors() {
-                for diag in parse.into_diagnostics() {
-                    let error = diag
-                        .with_file_path(file.clone())
-                        .with_file_source_code(code);
-                    write_diagnostic(code, error)?;
-                }
-            } else {
-                let root = parse.tree();
+    Ok((
+        group,
+        rule,
+        strip_html(str::from_utf8(&content)?).into_bytes(),
+    ))
+}
 
-                let rule_filter = RuleFilter::Rule(group, rule);
-                let filter = AnalysisFilter {
-                    enabled_rules: Some(slice::from_ref(&rule_filter)),
-                    ..AnalysisFilter::default()
-                };
+/// Removes the HTML tags that the pages are allowed to contain, like the
+/// anchors of the examples, outside of the code, and the lines left empty by
+/// them, so that the documentation reads as plain text
+fn strip_html(content: &str) -> String {
+    let mut text = String::new();
+    let mut in_code_block = false;
+    for line in content.lines() {
+        let stripped = if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+            line.to_string()
+        } else if in_code_block {
+            line.to_string()
+        } else {
+            // The inline code is the odd segments when splitting on backticks
+            line.split('`')
+                .enumerate()
+                .map(|(index, segment)| match index % 2 {
+                    0 => strip_tags(segment),
+                    _ => segment.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("`")
+        };
+        if stripped.trim().is_empty() && (!line.trim().is_empty() || text.ends_with("\n\n")) {
+            continue;
+        }
+        text.push_str(&stripped);
+        text.push('\n');
+    }
+    text
+}
 
-                let mut options = AnalyzerOptions::default();
-                options.configuration.jsx_runtime = Some(JsxRuntime::default());
-                let (_, diagnostics) = biome_js_analyze::analyze(
-                    &root,
-                    filter,
-                    &options,
-                    source_type,
-                    None,
-                    |signal| {
-                        if let Some(mut diag) = signal.diagnostic() {
-                            let category = diag.category().expect("linter diagnostic has no code");
-                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
-                                "If you see this error, it means you need to run cargo codegen-configuration",
-                            );
+/// Removes the tags of [KNOWN_MDX_TAGS] from the text
+fn strip_tags(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('<') {
+        result.push_str(&rest[..start]);
+        let tag = &rest[start + 1..];
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| !c.is_alphanumeric())
+            .next()
+            .unwrap_or_default();
+        match tag.find('>') {
+            Some(end) if KNOWN_MDX_TAGS.contains(&name) => rest = &tag[end + 1..],
+            _ => {
+                result.push('<');
+                rest = tag;
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
 
-                            for action in signal.actions() {
-                                if !action.is_suppression() {
-                                    rule_has_code_action = true;
-                                    diag = diag.add_code_suggestion(action.into());
-                                }
-                            }
+/// Asserts that analyzing `code` with `rule` emits a diagnostic whose
+/// description contains `expected_message`.
+///
+/// The code is analyzed like an example of the documentation of the rule:
+/// `info_string` is the info string of its code block, for example `ts` or
+/// `jsx,file=App.jsx`, which implies `expect_diagnostic`.
+pub fn assert_rule_message(
+    rule: &str,
+    info_string: &str,
+    code: &str,
+    expected_message: &str,
+) -> Result<()> {
+    let (group, rule, meta) = find_rule(rule)?;
 
-                            let error = diag
-                                .with_severity(severity)
-                                .with_file_path(file.clone())
-                                .with_file_source_code(code);
-                            let res = write_diagnostic(code, error);
+    let mut test = CodeBlockTest::from_str(info_string)?;
+    test.expect_diagnostic = true;
+    let outcome = assert_lint(
+        group,
+        rule,
+        &test,
+        code,
+        &mut Vec::new(),
+        meta.fix_kind.is_some(),
+        &DocsGenConfig::default(),
+    )?;
 
-                            // Abort the analysis on error
-                            if let Err(err) = res {
-                                return ControlFlow::Break(err);
-                            }
-                        }
+    ensure!(
+        outcome
+            .messages
+            .iter()
+            .any(|message| message.contains(expected_message)),
+        "the rule {rule} didn't emit a diagnostic containing `{expected_message}`, the diagnostics were: {:?}",
+        outcome.messages
+    );
 
-                        ControlFlow::Continue(())
-                    },
-                );
+    Ok(())
+}
 
-                // Result is Some(_) if analysis aborted with an error
-                for diagnostic in diagnostics {
-                    write_diagnostic(code, diagnostic)?;
-                }
-            }
+/// What was observed while analyzing a code block
+#[derive(Default)]
+struct LintOutcome {
+    /// The description of the diagnostics emitted for the code block
+    messages: Vec<String>,
+    /// The files edited by the code actions of the diagnostics
+    edits: FileEdits,
+    /// The code block after applying the safe fixes of the rule
+    fixed_code: Option<String>,
+    /// The edits of the safe code actions, a single one is rendered inline
+    replacements: Vec<(String, String)>,
+}
 
-            if test.expect_diagnostic && rule_has_code_action && !has_fix_kind {
-                bail!("The rule '{}' emitted code actions via `action` function, but you didn't mark rule with `fix_kind`.", rule)
-            }
+/// The code of the sub-blocks of an example edited by code actions, before
+/// and after the fix, by path
+pub(crate) type FileEdits = BTreeMap<String, (String, String)>;
+
+/// Records the edit of a code action to the analyzed sub-block. The mutation
+/// of an action is made on the tree of the file it was emitted for, so an
+/// action can't edit the other sub-blocks of a multi-file example. When
+/// several actions edit the same sub-block only the first one is kept.
+fn record_action_edit<L: biome_rowan::Language>(
+    edits: &mut FileEdits,
+    file: &str,
+    code: &str,
+    action: &AnalyzerAction<L>,
+) {
+    edits.entry(file.to_string()).or_insert_with(|| {
+        let fixed_code = action.mutation.clone().commit().to_string();
+        (code.to_string(), fixed_code)
+    });
+}
 
-            if test.expect_diagnostic {
-                // Fail the test if the analysis didn't emit any diagnostic
-                ensure!(
-                    diagnostic_count == 1,
-                    "analysis returned no diagnostics.\n code snippet:\n {}",
-                    code
-                );
-            }
+/// Writes a diff of each edited sub-block, titled with the path of its file. The
+/// lines shared by the start and the end of the two versions of a file are
+/// the context of its diff.
+fn write_file_diffs(content: &mut Vec<u8>, edits: &FileEdits) -> io::Result<()> {
+    for (file, (before, after)) in edits {
+        let before = before.lines().collect::<Vec<_>>();
+        let after = after.lines().collect::<Vec<_>>();
+        let prefix = before
+            .iter()
+            .zip(&after)
+            .take_while(|(before, after)| before == after)
+            .count();
+        let suffix = before[prefix..]
+            .iter()
+            .rev()
+            .zip(after[prefix..].iter().rev())
+            .take_while(|(before, after)| before == after)
+            .count();
+
+        writeln!(content, "```diff title=\"{}\"", escape_fence_title(file))?;
+        for line in &before[..prefix] {
+            writeln!(content, " {line}")?;
         }
-        BlockType::Json => {
-            let parse = biome_json_parser::parse_json(code, JsonParserOptions::default());
-
-            if parse.has_errors() {
-                for diag in parse.into_diagnostics() {
-                    let error = diag
-                        .with_file_path(file.clone())
-                        .with_file_source_code(code);
-                    write_diagnostic(code, error)?;
-                }
-            } else {
-                let root = parse.tree();
+        for line in &before[prefix..before.len() - suffix] {
+            writeln!(content, "-{line}")?;
+        }
+        for line in &after[prefix..after.len() - suffix] {
+            writeln!(content, "+{line}")?;
+        }
+        for line in &before[before.len() - suffix..] {
+            writeln!(content, " {line}")?;
+        }
+        writeln!(content, "```")?;
+        writeln!(content)?;
+    }
 
-                let rule_filter = RuleFilter::Rule(group, rule);
-                let filter = AnalysisFilter {
-                    enabled_rules: Some(slice::from_ref(&rule_filter)),
-                    ..AnalysisFilter::default()
-                };
+    Ok(())
+}
 
-                let options = AnalyzerOptions::default();
-                let (_, diagnostics) = biome_json_analyze::analyze(
-                    &root,
-                    filter,
-                    &options,
-                    |signal| {
-                        if let Some(mut diag) = signal.diagnostic() {
-                            let category = diag.category().expect("linter diagnostic has no code");
-                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
-                                "If you see this error, it means you need to run cargo codegen-configuration",
-                            );
+/// The maximum number of safe fixes applied to an example, which stops fixes
+/// that would undo each other
+const MAX_SAFE_FIXES: usize = 100;
 
-                            for action in signal.actions() {
-                                if !action.is_suppression() {
-                                    rule_has_code_action = true;
-                                    diag = diag.add_code_suggestion(action.into());
-                                }
-                            }
+/// Returns the code after applying the safe fixes of the rule one after the
+/// other, like `biome check --write`: the code is analyzed again after each
+/// fix. `first_safe_fix` returns the code after applying the first safe fix
+/// of its analysis.
+fn apply_safe_fixes(
+    code: &str,
+    mut first_safe_fix: impl FnMut(&str) -> Option<String>,
+) -> Option<String> {
+    let mut fixed_code = first_safe_fix(code)?;
+    for _ in 1..MAX_SAFE_FIXES {
+        match first_safe_fix(&fixed_code) {
+            Some(next_code) if next_code != fixed_code => fixed_code = next_code,
+            _ => break,
+        }
+    }
 
-                            let error = diag
-                                .with_severity(severity)
-                                .with_file_path(file.clone())
-                                .with_file_source_code(code);
-                            let res = write_diagnostic(code, error);
+    Some(fixed_code)
+}
 
-                            // Abort the analysis on error
-                            if let Err(err) = res {
-                                return ControlFlow::Break(err);
-                            }
-                        }
+/// Stops the analysis at the first safe fix, with the code after applying it
+fn safe_fix<L: biome_rowan::Language>(signal: &dyn AnalyzerSignal<L>) -> ControlFlow<String> {
+    let fix = signal
+        .actions()
+        .find(|action| !action.is_suppression() && action.applicability == Applicability::Always);
+    match fix {
+        Some(fix) => ControlFlow::Break(fix.mutation.commit().to_string()),
+        None => ControlFlow::Continue(()),
+    }
+}
 
-                        ControlFlow::Continue(())
-                    },
-                );
+/// Checks that an expected diagnostic isn't rendered as an empty string,
+/// which would ship an empty, confusing block
+fn validate_rendered_diagnostic(rule: &str, rendered: &[u8], code: &str) -> Result<()> {
+    let rendered = str::from_utf8(rendered).unwrap_or_default();
+    ensure!(
+        !rendered.trim().is_empty(),
+        "the diagnostic of the rule {rule} rendered as an empty string, code snippet:\n\n{code}"
+    );
 
-                // Result is Some(_) if analysis aborted with an error
-                for diagnostic in diagnostics {
-                    write_diagnostic(code, diagnostic)?;
-                }
+    Ok(())
+}
 
-                if test.expect_diagnostic && rule_has_code_action && !has_fix_kind {
-                    bail!("The rule '{}' emitted code actions via `action` function, but you didn't mark rule with `fix_kind`.", rule)
-                }
+/// Returns the byte ranges of the code suppressed by the comments
+/// `biome-ignore-start` and `biome-ignore-end`, from the start of the line of
+/// the first comment to the end of the line of the second one.
+///
+/// Only the lines that are comments are considered, a string containing
+/// `biome-ignore-start` doesn't suppress anything.
+fn suppression_ranges(code: &str) -> Result<Vec<Range<usize>>> {
+    let mut ranges = Vec::new();
+    let mut starts = Vec::new();
+    let mut line_start = 0;
+    for line in code.split_inclusive('\n') {
+        let line_end = line_start + line.len();
+        let trimmed = line.trim();
+        if trimmed.starts_with("//") || trimmed.starts_with("/*") {
+            if trimmed.contains("biome-ignore-start") {
+                starts.push(line_start);
+            } else if trimmed.contains("biome-ignore-end") {
+                let Some(start) = starts.pop() else {
+                    bail!("the example has a `biome-ignore-end` comment without a matching `biome-ignore-start`, code snippet:\n\n{code}");
+                };
+                ranges.push(start..line_end);
             }
         }
-        BlockType::Css => {
-            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
-
-            if parse.has_errors() {
-                for diag in parse.into_diagnostics() {
-                    let error = diag
-                        .with_file_path(file.clone())
-                        .with_file_source_code(code);
-                    write_diagnostic(code, error)?;
-                }
-            } else {
-                let root = parse.tree();
+        line_start = line_end;
+    }
+    ensure!(
+        starts.is_empty(),
+        "the example has a `biome-ignore-start` comment without a matching `biome-ignore-end`, code snippet:\n\n{code}"
+    );
 
-                let rule_filter = RuleFilter::Rule(group, rule);
-                let filter = AnalysisFilter {
-                    enabled_rules: Some(slice::from_ref(&rule_filter)),
-                    ..AnalysisFilter::default()
-                };
+    Ok(ranges)
+}
 
-                let options = AnalyzerOptions::default();
-                let (_, diagnostics) = biome_css_analyze::analyze(
-                    &root,
-                    filter,
-                    &options,
-                    |signal| {
-                        if let Some(mut diag) = signal.diagnostic() {
-                            let category = diag.category().expect("linter diagnostic has no code");
-                            let severity = settings.get_current_settings().expect("project").get_severity_from_rule_code(category).expect(
-                                "If you see this error, it means you need to run cargo codegen-configuration",
-                            );
+/// The state of the analysis of an example, filled by the analyzer of its
+/// language
+struct Analysis<'a> {
+    group: &'static str,
+    rule: &'static str,
+    test: &'a CodeBlockTest,
+    config: &'a DocsGenConfig,
+    /// The buffer the diagnostics are rendered into
+    content: &'a mut Vec<u8>,
+    /// The path of the file of the example, used by its diagnostics
+    file: String,
+    settings: WorkspaceSettings,
+    /// Every diagnostic, rendered or not, checked against the expectation of
+    /// the example once the analysis is complete
+    diagnostics: Vec<biome_diagnostics::Error>,
+    rule_has_code_action: bool,
+    has_safe_fix: bool,
+    /// The titles of the code actions of the diagnostics, checked against
+    /// `expect_action`
+    action_titles: Vec<String>,
+    edits: FileEdits,
+    /// The text replaced by each safe code action, and its replacement
+    replacements: Vec<(String, String)>,
+    /// The code after applying the safe fixes of the rule
+    fixed_code: Option<String>,
+}
 
-                            for action in signal.actions() {
-                                if !action.is_suppression() {
-                                    rule_has_code_action = true;
-                                    diag = diag.add_code_suggestion(action.into());
-                                }
-                            }
+impl Analysis<'_> {
+    /// The options of the analyzer, with the configuration of the rules of
+    /// the example
+    fn analyzer_options(&self) -> AnalyzerOptions {
+        let mut options = AnalyzerOptions::default();
+        options.configuration.rules = to_analyzer_rules(
+            self.settings.get_current_settings().expect("project"),
+            Path::new(&self.file),
+        );
+        options
+    }
 
-                            let error = diag
-                                .with_severity(severity)
-                                .with_file_path(file.clone())
-                                .with_file_source_code(code);
-                            let res = write_diagnostic(code, error);
+    /// Renders the diagnostic. A runaway rule doesn't flood the page, the
+    /// diagnostics past the limit are only counted.
+    fn write_diagnostic(&mut self, code: &str, diag: biome_diagnostics::Error) -> Result<()> {
+        if self.diagnostics.len() < self.config.max_diagnostics {
+            let start = self.content.len();
+            self.config.diagnostic_backend.print(self.content, &diag)?;
+            if self.test.expect_diagnostic {
+                validate_rendered_diagnostic(self.rule, &self.content[start..], code)?;
+            }
+        }
 
-                            // Abort the analysis on error
-                            if let Err(err) = res {
-                                return ControlFlow::Break(err);
-                            }
-                        }
+        self.diagnostics.push(diag);
+        Ok(())
+    }
 
-                        ControlFlow::Continue(())
-                    },
-                );
+    /// Renders the errors of the parser
+    fn write_parse_diagnostics<D>(&mut self, code: &str, diagnostics: Vec<D>) -> Result<()>
+    where
+        D: Diagnostic + Send + Sync + 'static,
+    {
+        for diag in diagnostics {
+            let error = diag
+                .with_file_path(self.file.clone())
+                .with_file_source_code(code);
+            self.write_diagnostic(code, error)?;
+        }
 
-                // Result is Some(_) if analysis aborted with an error
-                for diagnostic in diagnostics {
-                    write_diagnostic(code, diagnostic)?;
-                }
+        Ok(())
+    }
 
-                if test.expect_diagnostic && rule_has_code_action && !has_fix_kind {
-                    bail!("The rule '{}' emitted code actions via `action` function, but you didn't mark rule with `fix_kind`.", rule)
+    /// Renders the diagnostic of a signal of the rule, with its code actions
+    fn record_signal<L: biome_rowan::Language>(
+        &mut self,
+        code: &str,
+        signal: &dyn AnalyzerSignal<L>,
+    ) -> ControlFlow<anyhow::Error> {
+        let Some(mut diag) = signal.diagnostic() else {
+            return ControlFlow::Continue(());
+        };
+        let category = diag.category().expect("linter diagnostic has no code");
+        // The example can show the rule at another severity than its default one
+        let severity = self.test.severity.unwrap_or_else(|| {
+            self.settings
+                .get_current_settings()
+                .expect("project")
+                .get_severity_from_rule_code(category)
+                .expect(
+                    "If you see this error, it means you need to run cargo codegen-configuration",
+                )
+        });
+
+        for action in signal.actions() {
+            if !action.is_suppression() {
+                self.rule_has_code_action = true;
+                record_action_edit(&mut self.edits, &self.file, code, &action);
+                if action.applicability == Applicability::Always {
+                    self.has_safe_fix = true;
+                    if let Some((range, edit)) = action.mutation.as_text_range_and_edit() {
+                        let before = &code[range];
+                        self.replacements
+                            .push((before.to_string(), edit.new_string(before)));
+                    }
                 }
+                self.action_titles.push(markup_to_string(&action.message));
+                diag = diag.add_code_suggestion(action.into());
             }
         }
-        // Foreign code blocks should be already ignored by tests
-        BlockType::Foreign(..) => {}
+
+        let error = diag
+            .with_severity(severity)
+            .with_file_path(self.file.clone())
+            .with_file_source_code(code);
+        // Abort the analysis on error
+        match self.write_diagnostic(code, error) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(err) => ControlFlow::Break(err),
+        }
     }
 
-    Ok(())
-}
+    /// Renders the diagnostics returned by the analyzer besides the signals,
+    /// once the analysis is complete. `aborted` is the error that aborted the
+    /// analysis, if any.
+    fn finish(
+        &mut self,
+        code: &str,
+        aborted: Option<anyhow::Error>,
+        diagnostics: Vec<biome_diagnostics::Error>,
+    ) -> Result<()> {
+        if let Some(err) = aborted {
+            return Err(err);
+        }
+        for diagnostic in diagnostics {
+            self.write_diagnostic(code, diagnostic)?;
+        }
 
-fn generate_reference(group: &'static str, buffer: &mut dyn io::Write) -> io::Result<()> {
-    let (group_name, description) = extract_group_metadata(group);
-    let description = markup_to_string(&description.to_owned());
-    let description = description.replace('\n', " ");
-    writeln!(
-        buffer,
-        "<li><code>{}</code>: {}</li>",
-        group_name.to_lowercase(),
-        description
-    )
+        Ok(())
+    }
 }
 
-fn extract_group_metadata(group: &str) -> (&str, Markup) {
-    match group {
-        "a11y" => (
-            "Accessibility",
-            markup! {
-                "Rules focused on preventing accessibility problems."
-            },
+/// Parses and analyzes a JavaScript or TypeScript example
+fn analyze_js(analysis: &mut Analysis, source: &CodeSource, code: &str) -> Result<()> {
+    let source_type = js_source_type(source);
+    // Temporary support for astro, svelte and vue code blocks
+    let (code, source_type) = match source_type.as_embedding_kind() {
+        EmbeddingKind::Astro => (
+            biome_service::file_handlers::AstroFileHandler::input(code),
+            JsFileSource::ts(),
         ),
-        "complexity" => (
-            "Complexity",
-            markup! {
-                "Rules that focus on inspecting complex code that could be simplified."
-            },
+        EmbeddingKind::Svelte => (
+            biome_service::file_handlers::SvelteFileHandler::input(code),
+            biome_service::file_handlers::SvelteFileHandler::file_source(code),
         ),
-        "correctness" => (
-            "Correctness",
-            markup! {
-                "Rules that detect code that is guaranteed to be incorrect or useless."
-            },
+        EmbeddingKind::Vue => (
+            biome_service::file_handlers::VueFileHandler::input(code),
+            biome_service::file_handlers::VueFileHandler::file_source(code),
         ),
-        "nursery" => (
-            "Nursery",
-            markup! {
-                "New rules that are still under development.
+        _ => (code, source_type),
+    };
+    let parser_options = analysis.test.parser_options.clone();
 
-Nursery rules require explicit opt-in via configuration on stable versions because they may still have bugs or performance problems.
-They are enabled by default on nightly builds, but as they are unstable their diagnostic severity may be set to either error or
-warning, depending on whether we intend for the rule to be recommended or not when it eventually gets stabilized.
-Nursery rules get promoted to other groups once they become stable or may be removed.
+    let parse = biome_js_parser::parse(code, source_type, parser_options.clone());
+    if parse.has_errors() {
+        return analysis.write_parse_diagnostics(code, parse.into_diagnostics());
+    }
+    if analysis.test.parse_only_reason.is_some() {
+        return Ok(());
+    }
 
-Rules that belong to this group "<Emphasis>"are not subject to semantic version"</Emphasis>"."
-            },
-        ),
-        "performance" => (
-            "Performance",
-            markup! {
-                "Rules catching ways your code could be written to run faster, or generally be more efficient."
-            },
-        ),
-        "security" => (
-            "Security",
-            markup! {
-                "Rules that detect potential security flaws."
-            },
-        ),
-        "style" => (
-            "Style",
-            markup! {
-                "Rules enforcing a consistent and idiomatic way of writing your code."
-            },
-        ),
-        "suspicious" => (
+    let rule_filter = RuleFilter::Rule(analysis.group, analysis.rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+    let mut options = analysis.analyzer_options();
+    options.configuration.jsx_runtime = Some(
+        analysis
+            .test
+            .framework
+            .map_or_else(JsxRuntime::default, Framework::jsx_runtime),
+    );
+    options.configuration.globals = analysis.test.globals.clone();
+    let (aborted, diagnostics) = biome_js_analyze::analyze(
+        &parse.tree(),
+        filter,
+        &options,
+        source_type,
+        None,
+        |signal| analysis.record_signal(code, signal),
+    );
+    analysis.finish(code, aborted, diagnostics)?;
+
+    if analysis.has_safe_fix {
+        analysis.fixed_code = apply_safe_fixes(code, |code| {
+            let parse = biome_js_parser::parse(code, source_type, parser_options.clone());
+            if parse.has_errors() {
+                return None;
+            }
+            let filter = AnalysisFilter {
+                enabled_rules: Some(slice::from_ref(&rule_filter)),
+                ..AnalysisFilter::default()
+            };
+            biome_js_analyze::analyze(&parse.tree(), filter, &options, source_type, None, safe_fix)
+                .0
+        });
+    }
+
+    Ok(())
+}
+
+/// Parses and analyzes a JSON example
+fn analyze_json(analysis: &mut Analysis, _source: &CodeSource, code: &str) -> Result<()> {
+    let parse = biome_json_parser::parse_json(code, JsonParserOptions::default());
+    if parse.has_errors() {
+        return analysis.write_parse_diagnostics(code, parse.into_diagnostics());
+    }
+    if analysis.test.parse_only_reason.is_some() {
+        return Ok(());
+    }
+
+    let rule_filter = RuleFilter::Rule(analysis.group, analysis.rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+    let options = analysis.analyzer_options();
+    let (aborted, diagnostics) =
+        biome_json_analyze::analyze(&parse.tree(), filter, &options, |signal| {
+            analysis.record_signal(code, signal)
+        });
+    analysis.finish(code, aborted, diagnostics)?;
+
+    if analysis.has_safe_fix {
+        analysis.fixed_code = apply_safe_fixes(code, |code| {
+            let parse = biome_json_parser::parse_json(code, JsonParserOptions::default());
+            if parse.has_errors() {
+                return None;
+            }
+            let filter = AnalysisFilter {
+                enabled_rules: Some(slice::from_ref(&rule_filter)),
+                ..AnalysisFilter::default()
+            };
+            biome_json_analyze::analyze(&parse.tree(), filter, &options, safe_fix).0
+        });
+    }
+
+    Ok(())
+}
+
+/// Parses and analyzes a CSS example
+fn analyze_css(analysis: &mut Analysis, _source: &CodeSource, code: &str) -> Result<()> {
+    let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+    if parse.has_errors() {
+        return analysis.write_parse_diagnostics(code, parse.into_diagnostics());
+    }
+    if analysis.test.parse_only_reason.is_some() {
+        return Ok(());
+    }
+
+    let rule_filter = RuleFilter::Rule(analysis.group, analysis.rule);
+    let filter = AnalysisFilter {
+        enabled_rules: Some(slice::from_ref(&rule_filter)),
+        ..AnalysisFilter::default()
+    };
+    let options = analysis.analyzer_options();
+    let (aborted, diagnostics) =
+        biome_css_analyze::analyze(&parse.tree(), filter, &options, |signal| {
+            analysis.record_signal(code, signal)
+        });
+    analysis.finish(code, aborted, diagnostics)?;
+
+    if analysis.has_safe_fix {
+        analysis.fixed_code = apply_safe_fixes(code, |code| {
+            let parse = biome_css_parser::parse_css(code, CssParserOptions::default());
+            if parse.has_errors() {
+                return None;
+            }
+            let filter = AnalysisFilter {
+                enabled_rules: Some(slice::from_ref(&rule_filter)),
+                ..AnalysisFilter::default()
+            };
+            biome_css_analyze::analyze(&parse.tree(), filter, &options, safe_fix).0
+        });
+    }
+
+    Ok(())
+}
+
+/// Parse and analyze the provided code block, and asserts that it emits
+/// exactly zero or one diagnostic depending on the value of `expect_diagnostic`.
+/// That diagnostic is then emitted as text into the `content` buffer
+fn assert_lint(
+    group: &'static str,
+    rule: &'static str,
+    test: &CodeBlockTest,
+    code: &str,
+    content: &mut Vec<u8>,
+    has_fix_kind: bool,
+    config: &DocsGenConfig,
+) -> Result<LintOutcome> {
+    // The diagnostics and the fixes of the example are rendered under the
+    // name of its file
+    let file = test
+        .file
+        .clone()
+        .unwrap_or_else(|| format!("{group}/{rule}.js"));
+
+    if test.ignore {
+        return Ok(LintOutcome::default());
+    }
+    if test.expect_diagnostic {
+        // Without this check an empty block fails later with a misleading
+        // "no diagnostics" error
+        ensure!(
+            !code.trim().is_empty(),
+            "code block is empty, a block marked with `expect_diagnostic` must contain the code that triggers the diagnostic"
+        );
+    }
+    // The analyzer drops the diagnostics of the suppressed code, so an
+    // example of a range suppression must expect a diagnostic to show that
+    // the code outside of the range is still reported
+    let suppressed_ranges = suppression_ranges(code)?;
+    ensure!(
+        suppressed_ranges.is_empty() || test.expect_diagnostic,
+        "the example suppresses a range of code, it must be marked with `expect_diagnostic` to show the diagnostic of the code outside of the range"
+    );
+    let mut settings = WorkspaceSettings::default();
+    let key = settings.insert_project(PathBuf::new());
+    settings.register_current_project(key);
+    if let Some(configuration) = &config.configuration {
+        settings
+            .get_current_settings_mut()
+            .merge_with_configuration(configuration.clone(), None, None, &[])?;
+    }
+    if let Some(options) = &test.options {
+        let options: Value = serde_json::from_str(options)
+            .with_context(|| format!("the options of the example of {rule} aren't valid JSON"))?;
+        let configuration = serde_json::json!({
+            "linter": { "rules": { group: { rule: { "level": "error", "options": options } } } }
+        });
+        let configuration: PartialConfiguration = serde_json::from_value(configuration)
+            .with_context(|| format!("the options of the example of {rule} are invalid"))?;
+        settings
+            .get_current_settings_mut()
+            .merge_with_configuration(configuration, None, None, &[])?;
+    }
+    // The language the example is analyzed as, which can differ from the
+    // language it's rendered with
+    let source = match test.analyzed_as.as_ref().unwrap_or(&test.block_type) {
+        BlockType::Code(source) => source.clone(),
+        BlockType::Markdown => {
+            // The fenced code blocks embedded in the snippet are analyzed as if
+            // they were examples of their own, sharing the expectation of the
+            // Markdown block
+            let mut messages = Vec::new();
+            let mut edits = FileEdits::new();
+            let mut replacements = Vec::new();
+            let mut has_analyzed_blocks = false;
+            let mut embedded = None;
+
+            for event in Parser::new(code) {
+                match event {
+                    Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(meta))) => {
+                        let mut embedded_test =
+                            CodeBlockTest::parse(meta.as_ref(), config.doc_languages)?;
+                        embedded_test.expect_diagnostic = test.expect_diagnostic;
+                        if embedded_test.framework.is_none() {
+                            embedded_test.framework = test.framework;
+                        }
+                        if embedded_test.globals.is_empty() {
+                            embedded_test.globals.clone_from(&test.globals);
+                        }
+                        embedded = Some((embedded_test, String::new()));
+                    }
+                    Event::Text(text) => {
+                        if let Some((_, block)) = &mut embedded {
+                            block.push_str(&text);
+                        }
+                    }
+                    Event::End(TagEnd::CodeBlock) => {
+                        if let Some((embedded_test, block)) = embedded.take() {
+                            has_analyzed_blocks |= !embedded_test.ignore;
+                            let outcome = assert_lint(
+                                group,
+                                rule,
+                                &embedded_test,
+                                &block,
+                                content,
+                                has_fix_kind,
+                                config,
+                            )
+                            .context("the analysis of an embedded code block failed")?;
+                            messages.extend(outcome.messages);
+                            edits.extend(outcome.edits);
+                            replacements.extend(outcome.replacements);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            ensure!(
+                has_analyzed_blocks || !test.expect_diagnostic,
+                "the Markdown code block doesn't contain any code block that can be analyzed"
+            );
+
+            return Ok(LintOutcome {
+                messages,
+                edits,
+                fixed_code: None,
+                replacements,
+            });
+        }
+        // Foreign code blocks should be already ignored by tests
+        BlockType::Foreign(..) => return Ok(LintOutcome::default()),
+    };
+
+    let mut analysis = Analysis {
+        group,
+        rule,
+        test,
+        config,
+        content,
+        file,
+        settings,
+        diagnostics: Vec::new(),
+        rule_has_code_action: false,
+        has_safe_fix: false,
+        action_titles: Vec::new(),
+        edits: FileEdits::new(),
+        replacements: Vec::new(),
+        fixed_code: None,
+    };
+    (source.language.analyze)(&mut analysis, &source, code)?;
+    let Analysis {
+        content,
+        diagnostics: all_diagnostics,
+        rule_has_code_action,
+        action_titles,
+        edits,
+        replacements,
+        fixed_code,
+        ..
+    } = analysis;
+
+    if test.expect_diagnostic && rule_has_code_action && !has_fix_kind {
+        bail!("The rule '{}' emitted code actions via `action` function, but you didn't mark rule with `fix_kind`.", rule)
+    }
+    // The examples with suppressed ranges are checked below, with a more
+    // precise message
+    if test.expect_diagnostic && suppressed_ranges.is_empty() {
+        // Fail the test if the analysis didn't emit any diagnostic
+        ensure!(
+            !all_diagnostics.is_empty(),
+            "analysis returned no diagnostics.\n code snippet:\n {}",
+            code
+        );
+    }
+
+    if !suppressed_ranges.is_empty() {
+        let spans = all_diagnostics
+            .iter()
+            .filter_map(|diag| diag.location().span)
+            .map(|span| usize::from(span.start())..usize::from(span.end()));
+        let mut has_reported_code = false;
+        for span in spans {
+            let suppressed_range = suppressed_ranges
+                .iter()
+                .find(|range| range.start <= span.start && span.end <= range.end);
+            if let Some(range) = suppressed_range {
+                bail!(
+                    "the example has a diagnostic at {span:?}, inside the suppressed range {range:?}, code snippet:\n\n{code}"
+                );
+            }
+            has_reported_code = true;
+        }
+        ensure!(
+            has_reported_code,
+            "the example suppresses a range of code, but it doesn't have a diagnostic outside of the range, code snippet:\n\n{code}"
+        );
+    }
+
+    let diagnostic_count = all_diagnostics.len();
+    ensure!(
+        diagnostic_count < config.runaway_diagnostics,
+        "analysis returned {diagnostic_count} diagnostics, the rule is likely running away, code snippet:\n\n{code}"
+    );
+    let omitted_count = diagnostic_count.saturating_sub(config.max_diagnostics);
+    let more_diagnostics = fill(
+        config.locale.messages().more_diagnostics,
+        &[("count", omitted_count.to_string().as_str())],
+    );
+    if omitted_count > 0 {
+        writeln!(content, "{more_diagnostics}")?;
+    }
+    // Fail the test if the analysis returns more diagnostics than expected
+    if diagnostic_count > usize::from(test.expect_diagnostic) {
+        // Print the diagnostics to help the user
+        let mut console = biome_console::EnvConsole::default();
+        for diag in all_diagnostics.iter().take(config.max_diagnostics) {
+            console.println(
+                biome_console::LogLevel::Error,
+                markup! {
+                    {PrintDiagnostic::verbose(diag)}
+                },
+            );
+        }
+        if omitted_count > 0 {
+            console.println(
+                biome_console::LogLevel::Error,
+                markup! { {more_diagnostics} },
+            );
+        }
+
+        if test.expect_diagnostic {
+            bail!(
+                "analysis returned multiple diagnostics, code snippet: \n\n{}",
+                code
+            );
+        }
+        let category = all_diagnostics[0]
+            .category()
+            .map_or("", |category| category.name());
+        bail!(
+            "analysis returned an unexpected diagnostic, code `snippet:\n\n{:?}\n\n{}",
+            category,
+            code
+        );
+    }
+
+    if let Some(expected) = test.expect_fixes {
+        ensure!(
+            action_titles.len() == expected,
+            "the example of {rule} expects {expected} code actions, but its diagnostic has {}: {action_titles:?}",
+            action_titles.len()
+        );
+    }
+    if let Some(expected) = &test.expect_action {
+        ensure!(
+            action_titles.iter().any(|title| title == expected),
+            "the example of {rule} expects a code action titled `{expected}`, the titles of its code actions were: {action_titles:?}"
+        );
+    }
+
+    Ok(LintOutcome {
+        messages: all_diagnostics
+            .iter()
+            .map(|diag| PrintDescription(diag).to_string())
+            .collect(),
+        edits,
+        fixed_code,
+        replacements,
+    })
+}
+
+/// Generates a table that splits the released rules of each group into the
+/// recommended rules, enabled by default, and the opt-in rules. The nursery
+/// group is expected to be removed from `groups`, its rules are all opt-in.
+fn generate_recommended_rules_by_group(
+    groups: &BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
+    messages: &Messages,
+) -> String {
+    let link = |meta: &&RuleMetadata| {
+        format!(
+            "<a href='/linter/rules/{}'>{}</a>",
+            Case::Kebab.convert(meta.name),
+            meta.name
+        )
+    };
+
+    let mut buffer =
+        String::from("<!-- this file is auto generated, use `cargo lintdoc` to update it -->\n");
+    buffer.push_str("<table>\n");
+    buffer.push_str(&format!(
+        "<thead><tr><th>{}</th><th>{}</th><th>{}</th></tr></thead>\n",
+        messages.group_column, messages.recommended_column, messages.opt_in_column
+    ));
+    buffer.push_str("<tbody>\n");
+    for (group, rules) in groups {
+        let (recommended, opt_in): (Vec<_>, Vec<_>) = rules
+            .values()
+            .filter(|meta| meta.version != "next")
+            .partition(|meta| meta.recommended);
+        let (group_name, _) =
+            extract_group_metadata(group).expect("the groups are checked before the generation");
+        buffer.push_str(&format!(
+            "<tr><td>{group_name}</td><td>{}</td><td>{}</td></tr>\n",
+            recommended.iter().map(link).collect::<Vec<_>>().join(", "),
+            opt_in.iter().map(link).collect::<Vec<_>>().join(", ")
+        ));
+    }
+    buffer.push_str("</tbody>\n");
+    buffer.push_str("</table>\n");
+
+    buffer
+}
+
+/// The configuration of the linter that is equivalent to
+/// `"recommended": true`
+#[derive(Serialize)]
+struct RecommendedConfiguration<'a> {
+    linter: RecommendedLinterConfiguration<'a>,
+}
+
+#[derive(Serialize)]
+struct RecommendedLinterConfiguration<'a> {
+    rules: RecommendedRulesConfiguration<'a>,
+}
+
+#[derive(Serialize)]
+struct RecommendedRulesConfiguration<'a> {
+    recommended: bool,
+    /// The recommended rules of each group, enabled at their default severity
+    #[serde(flatten)]
+    groups: BTreeMap<&'a str, BTreeMap<&'a str, &'static str>>,
+}
+
+/// Generates the configuration that spells out the rules enabled by
+/// `"recommended": true`, so that users can see or copy what the preset
+/// enables
+fn generate_recommended_configuration(
+    groups: &BTreeMap<&'static str, BTreeMap<&'static str, RuleMetadata>>,
+) -> Result<String> {
+    let groups = groups
+        .iter()
+        .map(|(group, rules)| {
+            let rules = rules
+                .values()
+                .filter(|meta| meta.recommended && meta.version != "next")
+                .map(|meta| (meta.name, "on"))
+                .collect::<BTreeMap<_, _>>();
+            (*group, rules)
+        })
+        .filter(|(_, rules)| !rules.is_empty())
+        .collect();
+    let configuration = RecommendedConfiguration {
+        linter: RecommendedLinterConfiguration {
+            rules: RecommendedRulesConfiguration {
+                recommended: true,
+                groups,
+            },
+        },
+    };
+
+    Ok(serde_json::to_string_pretty(&configuration)?)
+}
+
+fn generate_reference(group: &'static str, buffer: &mut dyn io::Write) -> io::Result<()> {
+    let (group_name, description) =
+        extract_group_metadata(group).expect("the groups are checked before the generation");
+    let description = markup_to_string(&description.to_owned());
+    let description = description.replace('\n', " ");
+    writeln!(
+        buffer,
+        "<li><code>{}</code>: {}</li>",
+        group_name.to_lowercase(),
+        description
+    )
+}
+
+/// Returns the name and the description of a group, or `None` when the
+/// group is unknown
+fn extract_group_metadata(group: &str) -> Option<(&str, Markup)> {
+    let metadata = match group {
+        "a11y" => (
+            "Accessibility",
+            markup! {
+                "Rules focused on preventing accessibility problems."
+            },
+        ),
+        "complexity" => (
+            "Complexity",
+            markup! {
+                "Rules that focus on inspecting complex code that could be simplified."
+            },
+        ),
+        "correctness" => (
+            "Correctness",
+            markup! {
+                "Rules that detect code that is guaranteed to be incorrect or useless."
+            },
+        ),
+        "nursery" => (
+            "Nursery",
+            markup! {
+                "New rules that are still under development.
+
+Nursery rules require explicit opt-in via configuration on stable versions because they may still have bugs or performance problems.
+They are enabled by default on nightly builds, but as they are unstable their diagnostic severity may be set to either error or
+warning, depending on whether we intend for the rule to be recommended or not when it eventually gets stabilized.
+Nursery rules get promoted to other groups once they become stable or may be removed.
+
+Rules that belong to this group "<Emphasis>"are not subject to semantic version"</Emphasis>"."
+            },
+        ),
+        "performance" => (
+            "Performance",
+            markup! {
+                "Rules catching ways your code could be written to run faster, or generally be more efficient."
+            },
+        ),
+        "security" => (
+            "Security",
+            markup! {
+                "Rules that detect potential security flaws."
+            },
+        ),
+        "style" => (
+            "Style",
+            markup! {
+                "Rules enforcing a consistent and idiomatic way of writing your code."
+            },
+        ),
+        "suspicious" => (
             "Suspicious",
             markup! {
                 "Rules that detect code that is likely to be incorrect or useless."
             },
         ),
-        _ => panic!("Unknown group ID {group:?}"),
-    }
+        _ => return None,
+    };
+
+    Some(metadata)
 }
 
 pub fn write_markup_to_string(buffer: &mut dyn io::Write, markup: Markup) -> io::Result<()> {
@@ -1172,3 +5031,3235 @@ fn markup_to_string(markup: &MarkupBuf) -> String {
 
     String::from_utf8(buffer).expect("to have convert a buffer into a String")
 }
+
+/// The pages compared to snapshots are stored in `src/snapshots`. After an
+/// intentional change of the output, review and accept the new snapshots with
+/// `cargo insta review`, or run `INSTA_UPDATE=always cargo test` to accept
+/// them all.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::tests::RecordingRenderer;
+    use proptest::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Analyzes the code like an example of `noDebugger` with the info string
+    fn lint(info_string: &str, code: &str) -> Result<LintOutcome> {
+        let test = CodeBlockTest::from_str(info_string)?;
+        assert_lint(
+            "suspicious",
+            "noDebugger",
+            &test,
+            code,
+            &mut Vec::new(),
+            true,
+            &DocsGenConfig::default(),
+        )
+    }
+
+    /// The metadata of a released JavaScript rule
+    fn rule(name: &'static str, docs: &'static str) -> RuleMetadata {
+        RuleMetadata::new("1.0.0", name, docs).language("js")
+    }
+
+    /// Generates the pages of the rules, given with their group, into a
+    /// temporary directory
+    fn generate(
+        rules: Vec<(&'static str, RuleMetadata)>,
+        config: &DocsGenConfig,
+    ) -> Result<tempfile::TempDir> {
+        generate_with_extra(rules, "{}", config)
+    }
+
+    /// Generates the pages of the rules like [generate], with the extra
+    /// metadata given as JSON
+    fn generate_with_extra(
+        rules: Vec<(&'static str, RuleMetadata)>,
+        extra_metadata: &str,
+        config: &DocsGenConfig,
+    ) -> Result<tempfile::TempDir> {
+        let output_root = tempfile::tempdir()?;
+        generate_into(rules, extra_metadata, config, output_root.path())?;
+
+        Ok(output_root)
+    }
+
+    /// Generates the pages of the rules like [generate_with_extra], into an
+    /// existing directory
+    fn generate_into(
+        rules: Vec<(&'static str, RuleMetadata)>,
+        extra_metadata: &str,
+        config: &DocsGenConfig,
+        output_root: &Path,
+    ) -> Result<()> {
+        let mut visitor = LintRulesVisitor::default();
+        for (group, meta) in rules {
+            visitor.record_language(&meta);
+            visitor
+                .groups
+                .entry(group)
+                .or_default()
+                .insert(meta.name, meta);
+        }
+        write_rule_docs(
+            visitor,
+            &serde_json::from_str(extra_metadata)?,
+            config,
+            output_root,
+        )
+    }
+
+    /// The number of examples analyzed by [STUB_LANGUAGES]
+    static STUB_ANALYSES: AtomicUsize = AtomicUsize::new(0);
+
+    /// A language registered like the ones of Biome, whose registry has a
+    /// single rule and whose examples never have a diagnostic
+    const STUB_LANGUAGES: &[DocLanguage] = &[DocLanguage {
+        name: "stub",
+        rule_languages: &[RuleLanguage {
+            name: "stub",
+            description: |_| "Stub",
+            icon: "seti:stub",
+            label: |_| "Stub rule",
+            legend: |_| Some("indicates that the rule is applied to stub files."),
+            file_types: &["stub"],
+        }],
+        tokens: &[("stub", "stub")],
+        module_kind: |_| None,
+        visit_registry: |visitor| {
+            let meta = RuleMetadata::new(
+                "1.0.0",
+                "noStubRule",
+                "Disallow the stub syntax.\n\n## Examples\n\n```stub\nvalid\n```\n",
+            )
+            .language("stub");
+            visitor.record_language(&meta);
+            visitor
+                .groups
+                .entry("suspicious")
+                .or_default()
+                .insert(meta.name, meta);
+        },
+        label: |source| source.token.to_string(),
+        analyze: |_, _, _| {
+            STUB_ANALYSES.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        },
+    }];
+
+    fn read_page(output_root: &tempfile::TempDir, page: &str) -> String {
+        fs::read_to_string(
+            output_root
+                .path()
+                .join(format!("src/content/docs/linter/rules/{page}.md")),
+        )
+        .unwrap()
+    }
+
+    fn read_index(output_root: &tempfile::TempDir) -> String {
+        fs::read_to_string(
+            output_root
+                .path()
+                .join("src/content/docs/linter/rules/index.mdx"),
+        )
+        .unwrap()
+    }
+
+    /// Parses the documentation like the one of `noDebugger`, and returns what
+    /// was extracted from it with the content of the page
+    fn document<'a>(docs: &'a str, config: &DocsGenConfig) -> Result<(Documentation<'a>, String)> {
+        document_rule("suspicious", "noDebugger", docs, config)
+    }
+
+    /// Parses the documentation like the one of the fixable rule `group/rule`
+    fn document_rule<'a>(
+        group: &'static str,
+        rule: &'static str,
+        docs: &'a str,
+        config: &DocsGenConfig,
+    ) -> Result<(Documentation<'a>, String)> {
+        let mut content = Vec::new();
+        let documentation = parse_documentation(
+            group,
+            rule,
+            docs,
+            &mut content,
+            true,
+            &[],
+            &["js"],
+            None,
+            config,
+        )?;
+        Ok((documentation, String::from_utf8(content)?))
+    }
+
+    /// Parses the documentation like the one of `noDebugger`, returning the
+    /// content of the page
+    fn parse(docs: &str) -> Result<String> {
+        let (_, content) = document(docs, &DocsGenConfig::default())?;
+        Ok(content)
+    }
+
+    /// Renders the documentation fragments of `tests/doc-fragments`, the
+    /// fragments rejected by the parser are snapshotted with their error
+    #[test]
+    fn doc_fragments() {
+        insta::glob!("../tests/doc-fragments", "*.md", |path| {
+            let docs = fs::read_to_string(path).unwrap();
+            let page = match parse(&docs) {
+                Ok(page) => page,
+                Err(err) => format!("error: {err:#}"),
+            };
+            insta::assert_snapshot!(page);
+        });
+    }
+
+    #[test]
+    fn empty_invalid_example_is_rejected() {
+        let err = lint("js,expect_diagnostic", "\n").err().unwrap();
+        assert!(
+            err.to_string().contains("code block is empty"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn languages_select_a_subset_of_the_registries() {
+        let config = DocsGenConfig {
+            languages: vec![RegistryLanguage("js")],
+            ..DocsGenConfig::default()
+        };
+        assert!(config.includes(&DOC_LANGUAGES[0]));
+        assert!(!config.includes(&DOC_LANGUAGES[2]));
+        assert!(config.is_subset());
+        assert!(!DocsGenConfig::default().is_subset());
+
+        assert_eq!("json".parse(), Ok(RegistryLanguage("json")));
+        let err = "yaml".parse::<RegistryLanguage>().unwrap_err();
+        assert!(err.starts_with("unknown language `yaml`"), "{err}");
+        assert!("".parse::<RegistryLanguage>().is_err());
+        assert!("JS".parse::<RegistryLanguage>().is_err());
+
+        // Selecting a language twice selects it once
+        let config = DocsGenConfig {
+            languages: vec![RegistryLanguage("js"), RegistryLanguage("js")],
+            ..DocsGenConfig::default()
+        };
+        assert!(config.includes(&DOC_LANGUAGES[0]));
+        assert!(config.is_subset());
+
+        // Selecting every language generates every rule
+        let config = DocsGenConfig {
+            languages: DOC_LANGUAGES
+                .iter()
+                .map(|language| RegistryLanguage(language.name))
+                .collect(),
+            ..DocsGenConfig::default()
+        };
+        assert!(!config.is_subset());
+    }
+
+    #[test]
+    fn first_invalid_example_gives_the_diagnostic_message() {
+        let (documentation, _) = document(
+            "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n\n```js\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let message = documentation.representative_message.unwrap();
+        assert!(message.contains("debugger"), "{message}");
+    }
+
+    #[test]
+    fn reasons_of_the_ignored_examples_are_collected() {
+        let (documentation, _) = document(
+            "Disallow the use of `debugger`.\n\n```js,ignore=incomplete\ndebugger\n```\n\n```js,ignore\ndebugger\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(documentation.ignore_reasons, ["incomplete"]);
+
+        // A quoted reason can have spaces
+        let test = CodeBlockTest::from_str("js,ignore=\"needs a bundler\"").unwrap();
+        assert!(test.ignore);
+        assert_eq!(test.ignore_reason.as_deref(), Some("needs a bundler"));
+
+        let err = CodeBlockTest::from_str("js,ignore=").err().unwrap();
+        assert!(
+            err.to_string().contains("must give the reason"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn empty_registry_produces_an_empty_index() {
+        let report = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            warnings_report: Some(report.path().join("warnings.json")),
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate(Vec::new(), &config).unwrap();
+        assert!(!read_index(&output_root).contains("| Rule name |"));
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(report.path().join("warnings.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!([{
+                "rule": "registries",
+                "kind": "empty-registry",
+                "message": "no lint rules were found, the index of the rules is empty",
+            }])
+        );
+
+        // A documentation without rules is likely a broken build of the
+        // analyzer, the strict mode doesn't let it through
+        let config = DocsGenConfig {
+            strict: true,
+            ..DocsGenConfig::default()
+        };
+        let err = generate(Vec::new(), &config).unwrap_err();
+        assert!(
+            format!("{err:?}").contains("no lint rules were found"),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn registry_without_nursery_is_generated() {
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let index = read_index(&output_root);
+        assert!(index.contains("[noDebugger](/linter/rules/no-debugger)"));
+        assert!(!index.contains("data-group=\"nursery\""));
+    }
+
+    #[test]
+    fn code_blocks_of_markdown_examples_are_analyzed() {
+        let outcome = lint("md,expect_diagnostic", "# Title\n\n```js\ndebugger;\n```\n").unwrap();
+        assert_eq!(outcome.messages.len(), 1);
+
+        let err = lint(
+            "md,expect_diagnostic",
+            "# Title\n\n```js\nconst a = 1;\n```\n",
+        )
+        .err()
+        .unwrap();
+        assert!(
+            format!("{err:#}").contains("analysis returned no diagnostics"),
+            "unexpected error: {err:#}"
+        );
+    }
+
+    #[test]
+    fn fixes_of_a_multi_file_example_have_a_diff_per_sub_block() {
+        let content = parse(
+            "Disallow the use of `debugger`.\n\n````md,expect_diagnostic\n```js,file=src/index.js\nimport { a } from \"./a.js\";\ndebugger;\na();\n```\n\n```js,file=src/a.js\nexport function a() {\n  debugger;\n}\n```\n````\n",
+        )
+        .unwrap();
+
+        let index = content.find("```diff title=\"src/index.js\"\n").unwrap();
+        let a = content.find("```diff title=\"src/a.js\"\n").unwrap();
+        // The files are sorted by path
+        assert!(a < index, "{content}");
+        assert!(
+            content[index..].contains(" import { a } from \"./a.js\";\n-debugger;\n"),
+            "{content}"
+        );
+        assert!(
+            content[a..index].contains(" export function a() {\n-  debugger;\n"),
+            "{content}"
+        );
+
+        let mut content = Vec::new();
+        let edits = FileEdits::from([(
+            "src/\"quoted\".js".to_string(),
+            ("debugger;\n".to_string(), String::new()),
+        )]);
+        write_file_diffs(&mut content, &edits).unwrap();
+        let content = String::from_utf8(content).unwrap();
+        assert!(
+            content.starts_with("```diff title=\"src/\\\"quoted\\\".js\"\n-debugger;\n"),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn registered_languages_are_documented() {
+        let config = DocsGenConfig {
+            doc_languages: STUB_LANGUAGES,
+            ..DocsGenConfig::default()
+        };
+        let visitor = LintRulesVisitor::visit(&config);
+        let output_root = tempfile::tempdir().unwrap();
+        write_rule_docs(
+            visitor,
+            &ExtraMetadata::default(),
+            &config,
+            output_root.path(),
+        )
+        .unwrap();
+
+        // The example is recognized, analyzed and labelled by the language
+        let page = read_page(&output_root, "no-stub-rule");
+        assert!(
+            page.contains("data-category=\"lint/suspicious/noStubRule\""),
+            "{page}"
+        );
+        assert!(page.contains("```stub\nvalid\n```"), "{page}");
+        assert!(page.contains("**Stub**"), "{page}");
+        assert!(page.contains("languages: [stub]"), "{page}");
+        assert_eq!(STUB_ANALYSES.load(Ordering::SeqCst), 1);
+        // The index uses the icon of the language, and describes it
+        let index = read_index(&output_root);
+        assert!(index.contains("seti:stub"), "{index}");
+        assert!(
+            index.contains("indicates that the rule is applied to stub files."),
+            "{index}"
+        );
+
+        // The languages excluded from the generation aren't visited
+        let config = DocsGenConfig {
+            languages: vec![RegistryLanguage("js")],
+            ..config
+        };
+        let visitor = LintRulesVisitor::visit(&config);
+        assert!(visitor.groups.is_empty());
+
+        let err = "graphql".parse::<RegistryLanguage>().unwrap_err();
+        assert_eq!(
+            err,
+            "unknown language `graphql`, expected one of `js`, `json`, `css`"
+        );
+    }
+
+    #[test]
+    fn title_collision_is_detected() {
+        let err = generate(
+            vec![
+                ("correctness", rule("noDebugger", "Disallow `debugger`.")),
+                ("suspicious", rule("noDebugger", "Disallow `debugger`.")),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "these rules are in several groups, their pages would have the same title: noDebugger (correctness, suspicious)"
+            ),
+            "unexpected error: {err}"
+        );
+
+        let pages = [
+            ("correctness", "noDebugger", "---\ntitle: noDebugger\n---\n"),
+            ("suspicious", "noConsole", "---\ntitle: noConsole\n---\n"),
+            (
+                "nursery",
+                "noDebuggerStatement",
+                "---\ntitle: noDebugger\n---\n",
+            ),
+        ]
+        .map(|(group, rule, content)| (group, rule, content.to_string()));
+        let err = validate_unique_titles(&pages).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "these pages of the rules have the same title:\n- `noDebugger`: noDebugger (correctness), noDebuggerStatement (nursery)"
+        );
+    }
+
+    #[test]
+    fn validate_only_writes_no_file() {
+        let output_root = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            validate_only: true,
+            incremental: true,
+            cache_dir: Some(output_root.path().join("cache")),
+            warnings_report: Some(output_root.path().join("warnings.json")),
+            combined_html: Some(output_root.path().join("rules.html")),
+            ..DocsGenConfig::default()
+        };
+        generate_into(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap();
+
+        let entries = fs::read_dir(output_root.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect::<Vec<_>>();
+        assert!(entries.is_empty(), "unexpected files: {entries:?}");
+    }
+
+    #[test]
+    fn corrupted_cache_is_regenerated() {
+        let output_root = tempfile::tempdir().unwrap();
+        let cache_dir = output_root.path().join("cache");
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(cache_dir.join("rule-pages.json"), "\u{0}garbage").unwrap();
+        let config = DocsGenConfig {
+            incremental: true,
+            cache_dir: Some(cache_dir.clone()),
+            ..DocsGenConfig::default()
+        };
+
+        generate_into(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap();
+
+        let page = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/content/docs/linter/rules/no-debugger.md"),
+        )
+        .unwrap();
+        assert!(page.contains("title: noDebugger"), "{page}");
+        let cache: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(cache_dir.join("rule-pages.json")).unwrap())
+                .unwrap();
+        assert!(cache["entries"]["noDebugger"].is_object(), "{cache}");
+    }
+
+    #[test]
+    fn validate_only_fails_on_a_broken_example() {
+        let output_root = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            validate_only: true,
+            incremental: true,
+            cache_dir: Some(output_root.path().join("cache")),
+            ..DocsGenConfig::default()
+        };
+        let broken = || {
+            rule(
+                "noDebugger",
+                "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\nconst a = 1;\n```\n",
+            )
+        };
+
+        let err = generate_into(
+            vec![("suspicious", broken())],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("noDebugger"), "{err:?}");
+        // The cache is neither written nor read, a second run fails the same
+        assert!(!output_root.path().join("cache").exists());
+        generate_into(
+            vec![("suspicious", broken())],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn combined_html_converts_the_generated_pages() {
+        let output_root = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            combined_html: Some(output_root.path().join("rules.html")),
+            ..DocsGenConfig::default()
+        };
+        let meta = rule(
+            "noDebugger",
+            "Disallow the use of `debugger`.\n\n## Examples\n\n### Invalid\n\n```js,expect_diagnostic\ndebugger;\n```\n\n### Valid\n\n```js\nconst a = 1;\n```\n",
+        )
+        .recommended(true);
+        generate_into(
+            vec![("suspicious", meta)],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap();
+
+        let html = fs::read_to_string(output_root.path().join("rules.html")).unwrap();
+        assert!(!html.contains(":::"), "{html}");
+        assert!(!html.contains("<Icon"), "{html}");
+        assert!(html.contains("<aside class=\"note\">"), "{html}");
+        assert!(html.contains("<h3 id=\"no-debugger-invalid\">"), "{html}");
+        assert!(html.contains("<h3 id=\"no-debugger-valid\">"), "{html}");
+        let links = html
+            .split("href=\"#")
+            .skip(1)
+            .map(|link| link.split('"').next().unwrap())
+            .collect::<Vec<_>>();
+        assert!(!links.is_empty(), "{html}");
+        for anchor in links {
+            assert!(
+                html.contains(&format!("id=\"{anchor}\"")),
+                "no anchor for the link to #{anchor}: {html}"
+            );
+        }
+    }
+
+    #[test]
+    fn diagnostics_are_rendered_by_the_backend() {
+        let docs = "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n";
+        let (_, html) = document(docs, &DocsGenConfig::default()).unwrap();
+        assert!(html.contains("<pre class=\"language-text\">"), "{html}");
+
+        let config = DocsGenConfig {
+            diagnostic_backend: DiagnosticBackend::Text,
+            ..DocsGenConfig::default()
+        };
+        let (_, text) = document(docs, &config).unwrap();
+        assert!(text.contains("```text\n"), "{text}");
+        assert!(!text.contains("<pre"), "{text}");
+        assert!(text.contains("lint/suspicious/noDebugger"), "{text}");
+
+        assert_eq!("text".parse(), Ok(DiagnosticBackend::Text));
+        assert!("json".parse::<DiagnosticBackend>().is_err());
+    }
+
+    #[test]
+    fn site_constructs_go_through_the_renderer() {
+        let recorder = RecordingRenderer::default();
+        let config = DocsGenConfig {
+            renderer: Renderer::new(recorder.clone()),
+            ..DocsGenConfig::default()
+        };
+        let meta = rule(
+            "noDebugger",
+            "Disallow the use of `debugger`.\n\n## Why is this bad?\n\nIt stops the execution.\n",
+        )
+        .recommended(true)
+        .fix_kind(FixKind::Safe);
+        let output_root = generate(vec![("suspicious", meta)], &config).unwrap();
+
+        let page = read_page(&output_root, "no-debugger");
+        let index = read_index(&output_root);
+        for content in [&page, &index] {
+            assert!(!content.contains(":::"), "{content}");
+            assert!(!content.contains("<Icon"), "{content}");
+        }
+        let calls = recorder.calls();
+        for call in [
+            "write_index_imports",
+            "open_aside note",
+            "open_aside tip",
+            "close_aside",
+            "icon approve-check-circle",
+            "icon seti:config",
+            "icon seti:javascript",
+        ] {
+            assert!(calls.iter().any(|recorded| recorded == call), "{calls:?}");
+        }
+    }
+
+    #[test]
+    fn unreleased_rules_are_not_counted() {
+        let unreleased =
+            RuleMetadata::new("next", "noConsole", "Disallow the use of `console`.").language("js");
+        let output_root = generate(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow the use of `debugger`."),
+                ),
+                ("suspicious", unreleased),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let number_of_rules = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/NumberOfRules.astro"),
+        )
+        .unwrap();
+        assert!(number_of_rules.ends_with("\n1"), "{number_of_rules}");
+        let index = read_index(&output_root);
+        assert_eq!(
+            index.lines().filter(|line| is_rule_row(line)).count(),
+            1,
+            "{index}"
+        );
+    }
+
+    #[test]
+    fn unreleased_rules_are_generated_for_previews() {
+        let rules = || {
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow the use of `debugger`."),
+                ),
+                (
+                    "suspicious",
+                    RuleMetadata::new("next", "noConsole", "Disallow the use of `console`.")
+                        .language("js"),
+                ),
+            ]
+        };
+        let page_path = |output_root: &tempfile::TempDir| {
+            output_root
+                .path()
+                .join("src/content/docs/linter/rules/no-console.md")
+        };
+
+        let output_root = generate(rules(), &DocsGenConfig::default()).unwrap();
+        assert!(!page_path(&output_root).exists());
+        assert!(!read_index(&output_root).contains("no-console"));
+
+        let config = DocsGenConfig {
+            include_unreleased: true,
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate(rules(), &config).unwrap();
+        let messages = Locale::En.messages();
+        let page = read_page(&output_root, "no-console");
+        assert!(page.contains(messages.unreleased), "{page}");
+        let index = read_index(&output_root);
+        assert_eq!(
+            index.lines().filter(|line| is_rule_row(line)).count(),
+            2,
+            "{index}"
+        );
+        assert!(
+            index.contains(&format!(
+                "[noConsole](/linter/rules/no-console)</span> <span class=\"new-rule-badge\">{}</span>",
+                messages.unreleased_badge
+            )),
+            "{index}"
+        );
+    }
+
+    #[test]
+    fn recommended_unreleased_rule_is_rejected() {
+        let meta = RuleMetadata::new("next", "noDebugger", "Disallow the use of `debugger`.")
+            .language("js")
+            .recommended(true);
+        let err = generate(vec![("suspicious", meta)], &DocsGenConfig::default()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("the rule is recommended but it isn't released yet"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn rule_name_must_round_trip_through_its_slug() {
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(output_root
+            .path()
+            .join("src/content/docs/linter/rules/no-debugger.md")
+            .exists());
+
+        let err = generate(
+            vec![(
+                "suspicious",
+                rule("no_debugger", "Disallow the use of `debugger`."),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("doesn't convert back to the name of the rule"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn unknown_group_is_rejected() {
+        let err = generate(
+            vec![("magic", rule("noMagic", "Disallow the use of magic."))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "the groups `magic` don't have metadata, add them to `extract_group_metadata`"
+            ),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn unclosed_bold_is_rejected() {
+        let err = parse("Disallow the use of `debugger`.\n\nThis is **bold.\n").unwrap_err();
+        assert!(
+            err.to_string().contains("unclosed formatting marker `*`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn escaped_and_intraword_markers_are_allowed() {
+        let content = parse(
+            "Disallow the use of `debugger`.\n\nThe \\*name\\* of a snake_case variable, 2 * 3 and **bold** text.\n",
+        )
+        .unwrap();
+        assert!(content.contains("**bold**"));
+    }
+
+    #[test]
+    fn examples_have_an_anchor() {
+        let content = parse(
+            "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n\n```js\nconst a = 1;\n```\n",
+        )
+        .unwrap();
+        assert!(
+            content.contains("<a id=\"example-1\"></a>\n\n```js"),
+            "{content}"
+        );
+        assert!(
+            content.contains("<a id=\"example-2\"></a>\n\n```js"),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn file_of_the_example_is_its_title_and_the_path_of_its_diagnostics() {
+        let config = DocsGenConfig {
+            diagnostic_backend: DiagnosticBackend::Text,
+            ..DocsGenConfig::default()
+        };
+        let (_, content) = document(
+            "Disallow the use of `debugger`.\n\n```js,expect_diagnostic,file=src/app.js\ndebugger;\n```\n",
+            &config,
+        )
+        .unwrap();
+        assert!(content.contains(" title=\"src/app.js\"\n"), "{content}");
+        assert!(content.contains("src/app.js:1:1"), "{content}");
+    }
+
+    #[test]
+    fn unbalanced_brace_is_rejected_by_mdx_validation() {
+        let err = validate_mdx("The object {\n").unwrap_err();
+        assert!(
+            err.to_string().contains("1 unclosed brace"),
+            "unexpected error: {err}"
+        );
+        // The braces of code aren't expressions
+        validate_mdx("The code `{` is valid.\n\n```js\n{\n```\n").unwrap();
+    }
+
+    #[test]
+    fn extra_frontmatter_is_written() {
+        let rules = || {
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )]
+        };
+        let output_root = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noDebugger": { "frontmatter": { "sidebar": { "order": 1 } } } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(page.contains("sidebar:\n  order: 1\n---\n"), "{page}");
+
+        let err = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noDebugger": { "frontmatter": { "title": "Debugger" } } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .err()
+        .unwrap();
+        assert!(
+            format!("{err:#}").contains("reserved key `title`"),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn empty_rendered_diagnostic_is_rejected() {
+        let err = validate_rendered_diagnostic("noDebugger", b" \n", "debugger;").unwrap_err();
+        assert!(
+            err.to_string().contains("rendered as an empty string"),
+            "unexpected error: {err}"
+        );
+        assert!(validate_rendered_diagnostic("noDebugger", b"", "debugger;").is_err());
+        validate_rendered_diagnostic("noDebugger", b"lint/suspicious/noDebugger", "debugger;")
+            .unwrap();
+
+        // The diagnostic of an example is checked once it's rendered
+        let outcome = lint("js,expect_diagnostic", "debugger;\n").unwrap();
+        assert_eq!(outcome.messages.len(), 1);
+    }
+
+    #[test]
+    fn nursery_rules_are_described_for_the_channel() {
+        let rules = || {
+            vec![(
+                "nursery",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )]
+        };
+        let output_root = generate(rules(), &DocsGenConfig::default()).unwrap();
+        assert!(read_page(&output_root, "no-debugger").contains("explicit opt-in"));
+        assert!(!read_index(&output_root).contains("name=\"moon\""));
+
+        let config = DocsGenConfig {
+            channel: Channel::Nightly,
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate(rules(), &config).unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(
+            page.contains("enabled by default on nightly builds, where its diagnostics are emitted as **warnings**"),
+            "{page}"
+        );
+        assert!(read_index(&output_root).contains("name=\"moon\""));
+
+        assert_eq!("nightly".parse(), Ok(Channel::Nightly));
+        assert!("beta".parse::<Channel>().is_err());
+    }
+
+    #[test]
+    fn rule_message_is_asserted() {
+        assert_rule_message("noDebugger", "js", "debugger;", "debugger").unwrap();
+
+        let err = assert_rule_message("noDebugger", "js", "debugger;", "console").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("didn't emit a diagnostic containing `console`"),
+            "unexpected error: {err}"
+        );
+
+        // The code is an invalid example, it must emit a diagnostic
+        assert!(assert_rule_message("noDebugger", "js", "const a = 1;", "debugger").is_err());
+        // The info string is parsed like the one of a code block
+        let err = assert_rule_message("noDebugger", "js,severity=fatal", "debugger;", "debugger")
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("unknown severity `fatal`"),
+            "unexpected error: {err}"
+        );
+
+        let err = assert_rule_message("noUnknownRule", "js", "debugger;", "debugger").unwrap_err();
+        assert_eq!(err.to_string(), "the rule noUnknownRule doesn't exist");
+    }
+
+    #[test]
+    fn options_schema_is_linked_for_the_releases() {
+        assert_eq!(
+            options_schema_url("1.9.0", "NamingConventionOptions").as_deref(),
+            Some("/schemas/1.9.0/schema.json#/definitions/NamingConventionOptions")
+        );
+        assert_eq!(options_schema_url("0.0.0", "NamingConventionOptions"), None);
+    }
+
+    #[test]
+    fn single_word_fixes_are_rendered_inline() {
+        let (_, page) = document_rule(
+            "style",
+            "useConst",
+            "Require `const` declarations.\n\n```js,expect_diagnostic,output\nlet a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(page.contains("Output: `let` → `const`\n"), "{page}");
+
+        let (_, page) = document_rule(
+            "style",
+            "useConst",
+            "Require `const` declarations.\n\n```js,expect_diagnostic,output\nlet a = 1;\n```\n",
+            &DocsGenConfig {
+                locale: Locale::Fr,
+                ..DocsGenConfig::default()
+            },
+        )
+        .unwrap();
+        assert!(page.contains("Résultat : `let` → `const`\n"), "{page}");
+
+        // The inline output is derived from the edit of the code action
+        assert_eq!(
+            inline_replacement(&[("let".to_string(), "const".to_string())]),
+            Some(("let", "const"))
+        );
+        // Several code actions are rendered as a code block
+        assert_eq!(
+            inline_replacement(&[
+                ("let".to_string(), "const".to_string()),
+                ("let".to_string(), "const".to_string()),
+            ]),
+            None
+        );
+        assert_eq!(inline_replacement(&[]), None);
+
+        assert_eq!(
+            single_line_edit("let a = 1;\n", "const a = 1;\n"),
+            Some(("let", "const"))
+        );
+        assert_eq!(
+            single_line_edit("foo(bar);\n", "foo(baz);\n"),
+            Some(("bar", "baz"))
+        );
+        // The fix changes several lines, it's rendered as a code block
+        assert_eq!(
+            single_line_edit("let a = 1;\nlet b = 2;\n", "const a = 1;\nconst b = 2;\n"),
+            None
+        );
+    }
+
+    #[test]
+    fn examples_are_titled_with_a_default_file_name() {
+        let docs = "Disallow `debugger`.\n\n```ts\nconst a: number = 1;\n```\n\n```js,file=src/app.js\nconst a = 1;\n```\n";
+        let config = DocsGenConfig {
+            default_file_titles: true,
+            ..DocsGenConfig::default()
+        };
+        let (_, content) = document(docs, &config).unwrap();
+        assert!(content.contains("```ts title=\"index.ts\"\n"), "{content}");
+        assert!(
+            content.contains("```js title=\"src/app.js\"\n"),
+            "{content}"
+        );
+
+        let (_, content) = document(docs, &DocsGenConfig::default()).unwrap();
+        assert!(content.contains("```ts\n"), "{content}");
+        assert!(!content.contains("index.ts"), "{content}");
+    }
+
+    #[test]
+    fn unbalanced_lists_are_rejected() {
+        let mut lists = Lists::default();
+        assert!(!lists.close());
+        assert_eq!(lists.open_item(0), None);
+        assert_eq!(lists.close_item(), None);
+
+        lists.open(Some(u64::MAX));
+        assert_eq!(
+            lists.open_item(0).as_deref(),
+            Some("18446744073709551615. ")
+        );
+        assert_eq!(lists.close_item(), Some((0, 22, false)));
+        assert_eq!(lists.close_item(), None);
+        lists.open(None);
+        assert_eq!(lists.open_item(4).as_deref(), Some("- "));
+        assert!(lists.close());
+        assert!(lists.close());
+        assert!(!lists.close());
+        assert_eq!(lists.depth(), 0);
+
+        // The nested list is indented under the text of the numbered item
+        let content = parse("Disallow `debugger`.\n\n1. first\n   - nested\n2. second\n").unwrap();
+        assert!(content.contains("1. first\n   - nested\n"), "{content}");
+        // The numbers of the outer list go on after the nested list
+        assert!(content.contains("2. second\n"), "{content}");
+    }
+
+    #[test]
+    fn before_after_blocks_show_the_fixed_code() {
+        let (_, page) = document_rule(
+            "style",
+            "useConst",
+            "```js,expect_diagnostic,before_after\nlet a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(page.contains("<div class=\"fix-comparison\">"), "{page}");
+        assert!(page.contains("```js\nconst a = 1;\n```"), "{page}");
+
+        let err = document_rule(
+            "style",
+            "useConst",
+            "```js,before_after\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("must expect a diagnostic"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn unknown_icons_are_rejected() {
+        let extra_metadata: ExtraMetadata =
+            serde_json::from_str(r#"{ "knownIcons": ["rocket"] }"#).unwrap();
+        let config = DocsGenConfig::default();
+        validate_icons(
+            "<Icon name=\"seti:json\" label=\"JSON\" />",
+            &extra_metadata,
+            &config,
+        )
+        .unwrap();
+        validate_icons("<Icon name=\"rocket\" />", &extra_metadata, &config).unwrap();
+
+        let err =
+            validate_icons("\n<Icon name=\"seti:rust\" />", &extra_metadata, &config).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("line 2 references the unknown icon `seti:rust`"),
+            "unexpected error: {err}"
+        );
+
+        let err =
+            validate_icons("Text\n\n<Icon name=\"rocket", &extra_metadata, &config).unwrap_err();
+        assert_eq!(
+            format!("{err:#}"),
+            "line 3 contains an unterminated icon: the name of the icon `rocket` isn't terminated"
+        );
+    }
+
+    #[test]
+    fn frameworks_of_the_rule_are_listed() {
+        let output_root = generate_with_extra(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )],
+            r#"{ "rules": { "noDebugger": { "frameworks": ["react", "vue"] } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(read_page(&output_root, "no-debugger").contains("Applies to: **React**, **Vue**\n"));
+
+        let test = CodeBlockTest::from_str("jsx,framework=react-classic").unwrap();
+        assert_eq!(test.framework, Some(Framework::ReactClassic));
+        assert!(CodeBlockTest::from_str("jsx,framework=angular").is_err());
+    }
+
+    #[test]
+    fn examples_are_analyzed_for_the_first_framework() {
+        // The classic JSX runtime uses the `React` import
+        let docs = "Disallow unused imports.\n\n```jsx\nimport React from \"react\";\nexport const a = <div />;\n```\n";
+        let analyze = |frameworks: &[Framework]| {
+            parse_documentation(
+                "correctness",
+                "noUnusedImports",
+                docs,
+                &mut Vec::new(),
+                true,
+                frameworks,
+                &["js"],
+                None,
+                &DocsGenConfig::default(),
+            )
+        };
+        analyze(&[Framework::ReactClassic, Framework::React]).unwrap();
+        assert!(analyze(&[Framework::React, Framework::ReactClassic]).is_err());
+        assert!(analyze(&[]).is_err());
+    }
+
+    /// Reads the files of the directory and of its subdirectories, keyed by
+    /// their path relative to the directory
+    fn read_files(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+        let mut files = BTreeMap::new();
+        let mut directories = vec![root.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            for entry in fs::read_dir(directory).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else {
+                    let content = fs::read(&path).unwrap();
+                    files.insert(path.strip_prefix(root).unwrap().to_path_buf(), content);
+                }
+            }
+        }
+
+        files
+    }
+
+    #[test]
+    fn generation_is_deterministic() {
+        use biome_analyze::RuleSource;
+
+        // Rules of several groups, recommended or not, with sources and fixes,
+        // so that every file built from a map or a set of rules is compared
+        let rules = || {
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow the use of `debugger`.")
+                        .recommended(true)
+                        .fix_kind(FixKind::Unsafe)
+                        .sources(&[RuleSource::Eslint("no-debugger")]),
+                ),
+                (
+                    "suspicious",
+                    rule("noConsole", "Disallow the use of `console`.")
+                        .sources(&[RuleSource::Eslint("no-console")]),
+                ),
+                (
+                    "style",
+                    rule("useConst", "Require `const` declarations.")
+                        .recommended(true)
+                        .fix_kind(FixKind::Safe)
+                        .sources(&[RuleSource::Eslint("prefer-const")]),
+                ),
+                (
+                    "complexity",
+                    rule("noUselessCatch", "Disallow useless `catch` clauses.")
+                        .recommended(true)
+                        .sources(&[RuleSource::Eslint("no-useless-catch")]),
+                ),
+            ]
+        };
+
+        let first_run = generate(rules(), &DocsGenConfig::default()).unwrap();
+        // The registries may visit the rules in another order
+        let mut reversed_rules = rules();
+        reversed_rules.reverse();
+        let second_run = generate(reversed_rules, &DocsGenConfig::default()).unwrap();
+
+        let first_files = read_files(first_run.path());
+        let second_files = read_files(second_run.path());
+        assert!(first_files.contains_key(Path::new("src/content/docs/linter/rules-sources.mdx")));
+        assert!(
+            first_files.contains_key(Path::new("src/components/generated/RecommendedRules.astro"))
+        );
+        let different_files = first_files
+            .keys()
+            .chain(second_files.keys())
+            .filter(|path| first_files.get(*path) != second_files.get(*path))
+            .collect::<Vec<_>>();
+        assert!(
+            different_files.is_empty(),
+            "the generation isn't deterministic, these files differ between two runs: {different_files:?}"
+        );
+    }
+
+    #[test]
+    fn links_to_anchors_of_the_page_are_checked() {
+        parse("## Options\n\nSee [the options](#options) and [the example](#example-1).\n\n```js,ignore\ndebugger;\n```\n").unwrap();
+
+        let err = parse("See [the options](#nope).\n").unwrap_err();
+        assert!(
+            err.to_string().contains("links to `#nope`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn properties_of_the_rule_are_in_the_frontmatter() {
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`.")
+                    .recommended(true)
+                    .fix_kind(FixKind::Unsafe),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(
+            page.contains(
+                "stability: stable\nfixable: unsafe\nlanguages: [js, jsx, ts, tsx]\nrecommended: true\n"
+            ),
+            "{page}"
+        );
+    }
+
+    #[test]
+    fn diagnostics_past_the_limit_are_summarized() {
+        let test = CodeBlockTest::from_str("js,expect_diagnostic").unwrap();
+        let config = DocsGenConfig {
+            max_diagnostics: 3,
+            runaway_diagnostics: 10,
+            ..DocsGenConfig::default()
+        };
+        let lint = |code: &str, content: &mut Vec<u8>| {
+            assert_lint(
+                "suspicious",
+                "noDebugger",
+                &test,
+                code,
+                content,
+                true,
+                &config,
+            )
+            .err()
+            .unwrap()
+        };
+
+        // Past the rendering limit, the block is truncated and fails like
+        // any block with several diagnostics
+        let mut content = Vec::new();
+        let err = lint(&"debugger;\n".repeat(5), &mut content);
+        assert!(
+            err.to_string()
+                .contains("analysis returned multiple diagnostics"),
+            "unexpected error: {err}"
+        );
+        let content = String::from_utf8(content).unwrap();
+        assert_eq!(content.matches("lint/suspicious/noDebugger").count(), 3);
+        assert!(content.ends_with("…and 2 more\n"), "{content}");
+
+        // A runaway rule fails before its diagnostics are printed
+        let err = lint(&"debugger;\n".repeat(10), &mut Vec::new());
+        assert!(
+            err.to_string()
+                .contains("analysis returned 10 diagnostics, the rule is likely running away"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn rationale_is_rendered_in_an_aside() {
+        let docs = "## Why is this bad?\n\nIt stops the program.\n\n## Options\n\nNone.\n";
+        let page = parse(docs).unwrap();
+        assert!(
+            page.contains(":::tip[Why is this bad?]\nIt stops the program.\n\n:::\n\n## Options"),
+            "{page}"
+        );
+
+        let config = DocsGenConfig {
+            rationale_aside: AsideType::Caution,
+            ..DocsGenConfig::default()
+        };
+        let (_, page) = document(docs, &config).unwrap();
+        assert!(page.contains(":::caution[Why is this bad?]\n"), "{page}");
+        assert!("warning".parse::<AsideType>().is_err());
+    }
+
+    #[test]
+    fn changes_are_sorted_by_version() {
+        let rules = || {
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )]
+        };
+        let output_root = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noDebugger": { "changes": [
+                { "version": "1.2.0", "note": "a" },
+                { "version": "1.10.0", "note": "b" }
+            ] } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(
+            page.contains("Changes: \n- **v1.10.0**: b\n- **v1.2.0**: a\n"),
+            "{page}"
+        );
+
+        let err = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noDebugger": { "changes": [{ "version": "next", "note": "a" }] } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains(
+                "the version `next` of a change of the rule isn't a valid semver version"
+            ),
+            "{err:#}"
+        );
+    }
+
+    #[test]
+    fn diagnostic_category_is_structured_and_has_an_anchor() {
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow the use of `debugger`."),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(read_page(&output_root, "no-debugger").contains(
+            "<p id=\"lint-suspicious-no-debugger\" data-category=\"lint/suspicious/noDebugger\"><strong>Diagnostic Category: <a href=\"#lint-suspicious-no-debugger\"><code>lint/suspicious/noDebugger</code></a></strong></p>"
+        ));
+    }
+
+    #[test]
+    fn globals_are_defined_in_the_environment_of_the_example() {
+        let lint_undeclared = |info_string: &str| {
+            let test = CodeBlockTest::from_str(info_string).unwrap();
+            assert_lint(
+                "correctness",
+                "noUndeclaredVariables",
+                &test,
+                "foo(bar);\n",
+                &mut Vec::new(),
+                false,
+                &DocsGenConfig::default(),
+            )
+        };
+
+        let test = CodeBlockTest::from_str("js,global=foo,global=bar").unwrap();
+        assert_eq!(test.globals, ["foo", "bar"]);
+        lint_undeclared("js,global=foo,global=bar").unwrap();
+        assert!(lint_undeclared("js,global=foo").is_err());
+    }
+
+    #[test]
+    fn environment_of_the_example_defines_its_globals() {
+        let lint_undeclared = |info_string: &str| {
+            let test = CodeBlockTest::from_str(info_string).unwrap();
+            assert_lint(
+                "correctness",
+                "noUndeclaredVariables",
+                &test,
+                "describe(\"sum\", () => {});\n",
+                &mut Vec::new(),
+                false,
+                &DocsGenConfig::default(),
+            )
+        };
+
+        assert!(lint_undeclared("js").is_err());
+        lint_undeclared("js,expect_diagnostic").unwrap();
+        lint_undeclared("js,env=jest").unwrap();
+
+        let err = CodeBlockTest::from_str("js,env=deno").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown environment `deno`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn short_examples_are_inlined_in_the_index() {
+        let config = DocsGenConfig {
+            inline_examples: true,
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule(
+                    "noDebugger",
+                    "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n",
+                ),
+            )],
+            &config,
+        )
+        .unwrap();
+        let index = read_index(&output_root);
+        assert!(
+            index.contains("| Rule name | Description | Properties | Example |"),
+            "{index}"
+        );
+        assert!(index.contains(" <code>debugger;</code> |\n"), "{index}");
+        assert_eq!(escape_table_cell("a | {b}"), "a &#124; &#123;b&#125;");
+    }
+
+    #[test]
+    fn parse_only_examples_are_parsed_but_not_analyzed() {
+        let (documentation, _) = document(
+            "```js,parse_only=the_debugger_is_expected\ndebugger;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            documentation.ignore_reasons,
+            ["not analyzed: the_debugger_is_expected"]
+        );
+
+        assert!(lint("js,parse_only=syntax", "debugger;;(\n").is_err());
+        let err = parse("```js,expect_diagnostic,parse_only=syntax\ndebugger;\n```\n").unwrap_err();
+        assert!(
+            err.to_string().contains("can't expect a diagnostic"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn parser_options_are_enabled_by_the_info_string() {
+        let code = "class A {\n    constructor(@inject x) {}\n}\n";
+        assert!(lint("ts", code).is_err());
+        let test = CodeBlockTest::from_str("ts,parser=class_parameter_decorators").unwrap();
+        assert!(test.parser_options.parse_class_parameter_decorators);
+        lint("ts,parser=class_parameter_decorators", code).unwrap();
+
+        let err = CodeBlockTest::from_str("ts,parser=jsx_everywhere")
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "unknown parser option `jsx_everywhere`, the supported options are: `class_parameter_decorators`"
+        );
+    }
+
+    #[test]
+    fn output_panel_shows_the_fixed_code() {
+        let (_, content) = document_rule(
+            "style",
+            "useConst",
+            "Require `const` declarations.\n\n```js,expect_diagnostic,output\nlet a = 1;\nconsole.log(a);\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let (_, output) = content.split_once("title=\"Output\"").unwrap();
+        let output = &output[..output.find("```").unwrap()];
+        assert!(output.contains("const a = 1;\nconsole.log(a);"), "{output}");
+        assert!(!output.contains("let"), "{output}");
+
+        let err = parse("```js,output\nconst a = 1;\n```\n").unwrap_err();
+        assert!(
+            err.to_string().contains("must expect a diagnostic"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn invalid_examples_without_a_valid_one_are_warned() {
+        let (documentation, _) = document(
+            "Disallow `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            documentation.warnings,
+            [Warning::new(
+                WarningKind::MissingValidExample,
+                "the documentation has invalid examples but no valid example"
+            )]
+        );
+
+        let (documentation, _) = document(
+            "Disallow `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n\n```js\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(documentation.warnings.is_empty());
+    }
+
+    #[test]
+    fn rule_page_snapshot() {
+        let meta = rule(
+            "noExample",
+            "Disallow the use of `debugger`.\n\n## Why is this bad?\n\nIt stops the execution.\n",
+        )
+        .recommended(true)
+        .fix_kind(FixKind::Safe);
+        let output_root = generate(vec![("suspicious", meta)], &DocsGenConfig::default()).unwrap();
+        let page = read_page(&output_root, "no-example");
+        insta::assert_snapshot!("rule_page", page);
+    }
+
+    #[test]
+    fn analyzer_version_is_written() {
+        let output_root = generate(Vec::new(), &DocsGenConfig::default()).unwrap();
+        let version = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/AnalyzerVersion.astro"),
+        )
+        .unwrap();
+        assert!(version.ends_with(&format!("\n{ANALYZER_VERSION}\n")));
+        assert!(read_index(&output_root).contains("<AnalyzerVersion />"));
+    }
+
+    #[test]
+    fn long_diagnostics_are_collapsed() {
+        let docs = "Disallow the use of `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n";
+        let collapsed = |max_lines| {
+            let config = DocsGenConfig {
+                collapse_diagnostics: Some(max_lines),
+                ..DocsGenConfig::default()
+            };
+            let (_, content) = document(docs, &config).unwrap();
+            content.contains(
+                "<details>\n<summary>Show the full diagnostic</summary>\n\n<pre class=\"language-text\">",
+            ) && content.contains("</code></pre>\n\n</details>\n")
+        };
+        // The diagnostic is longer than a line, but shorter than a hundred
+        assert!(collapsed(1));
+        assert!(!collapsed(100));
+
+        let (_, content) = document(docs, &DocsGenConfig::default()).unwrap();
+        assert!(!content.contains("<details>"), "{content}");
+    }
+
+    #[test]
+    fn long_file_paths_wrap_after_their_separators() {
+        let (_, content) = document(
+            "Disallow the use of `debugger`.\n\n```js,expect_diagnostic,file=packages/frontend/src/components/navigation/sidebar/collapsible/NavigationSidebarItem.js\ndebugger;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let wrapped = "packages/<wbr>frontend/<wbr>src/<wbr>components/<wbr>navigation/<wbr>sidebar/<wbr>collapsible/<wbr>NavigationSidebarItem.js";
+        assert!(
+            content.contains(&format!(
+                "<a href=\"#example-1\" class=\"diagnostic-file\">{wrapped}</a>"
+            )),
+            "{content}"
+        );
+        // The whole path is shown, and it wraps only after its separators
+        assert!(
+            !content.contains("frontend/src/components/navigation"),
+            "{content}"
+        );
+
+        assert_eq!(
+            link_diagnostic_file(b"a/b.js a/b.js a/b.js", "a/b.js", "example-1"),
+            b"<a href=\"#example-1\" class=\"diagnostic-file\">a/<wbr>b.js</a> a/<wbr>b.js a/<wbr>b.js"
+        );
+    }
+
+    #[test]
+    fn file_of_the_diagnostics_links_to_its_example() {
+        let (_, content) = document(
+            "Disallow the use of `debugger`.\n\n```js\nconst a = 1;\n```\n\n```js,expect_diagnostic,file=src/app.js\ndebugger;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(
+            content
+                .contains("<a href=\"#example-2\" class=\"diagnostic-file\">src/<wbr>app.js</a>"),
+            "{content}"
+        );
+
+        assert_eq!(
+            link_diagnostic_file(b"&lt;a.js&gt; a.js", "<a.js>", "example-1"),
+            b"<a href=\"#example-1\" class=\"diagnostic-file\">&lt;a.js&gt;</a> a.js"
+        );
+    }
+
+    #[test]
+    fn oversized_docs_are_rejected() {
+        let config = DocsGenConfig {
+            max_docs_size: 16,
+            ..DocsGenConfig::default()
+        };
+        generate(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let err = generate(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            &config,
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:?}").contains("is 20 bytes long, more than the limit of 16 bytes"),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn every_language_of_the_rule_is_rendered() {
+        let docs = "Disallow `debugger`.";
+        let output_root = generate(
+            vec![
+                ("suspicious", rule("noDebugger", docs).recommended(true)),
+                (
+                    "suspicious",
+                    rule("noDebugger", docs).language("json").recommended(true),
+                ),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-debugger");
+        assert!(
+            page.contains("languages: [js, jsx, ts, tsx, json]\n"),
+            "{page}"
+        );
+        assert!(
+            page.contains(
+                "- This rule is applied to **JavaScript and super languages** and **JSON** files."
+            ),
+            "{page}"
+        );
+        // The rule is listed once in the index, with the icon of each language
+        let index = read_index(&output_root);
+        assert_eq!(
+            index.matches("(/linter/rules/no-debugger)").count(),
+            1,
+            "{index}"
+        );
+        assert!(index.contains("seti:javascript"), "{index}");
+        assert!(index.contains("seti:json"), "{index}");
+
+        // The file types shared by the languages are listed once
+        let output_root = generate(
+            vec![
+                ("suspicious", rule("noDebugger", docs)),
+                ("suspicious", rule("noDebugger", docs).language("ts")),
+                ("suspicious", rule("noDebugger", docs).language("ts")),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(page.contains("languages: [js, jsx, ts, tsx]\n"), "{page}");
+    }
+
+    #[test]
+    fn known_rule_is_explained() {
+        let (group, rule, content) = rule_explanation("noDebugger").unwrap();
+        assert_eq!((group, rule), ("suspicious", "noDebugger"));
+        let content = String::from_utf8(content).unwrap();
+        assert!(content.contains("debugger"), "{content}");
+        assert!(!content.contains("<a id="), "{content}");
+        assert!(!content.contains(":::"), "{content}");
+        assert!(!content.contains("\n\n\n"), "{content}");
+
+        let err = rule_explanation("noMagic").err().unwrap();
+        assert_eq!(err.to_string(), "the rule noMagic doesn't exist");
+    }
+
+    #[test]
+    fn explanation_is_plain_text() {
+        let text = strip_html(
+            "Disallow `<a>` without <code>href</code>.\n\n<a id=\"example-1\"></a>\n\n```jsx\n<a></a>\n```\n\n<details>\n<summary>Full diagnostic</summary>\n\n</details>\n",
+        );
+        assert_eq!(
+            text,
+            "Disallow `<a>` without href.\n\n```jsx\n<a></a>\n```\n\nFull diagnostic\n\n"
+        );
+    }
+
+    /// The constructs of Markdown, the links in particular, that are
+    /// combined into random documentations
+    const MARKDOWN_FRAGMENTS: &[&str] = &[
+        "Disallow",
+        " ",
+        "\n",
+        "\n\n",
+        "`debugger`",
+        "*",
+        "**",
+        "_",
+        "\\",
+        "# ",
+        "## ",
+        "- ",
+        "1. ",
+        "> ",
+        "| a | b |\n| - | - |\n",
+        "[",
+        "]",
+        "(",
+        ")",
+        "[link](https://biomejs.dev)",
+        "[link](/linter/rules/no-console)",
+        "[link](#options)",
+        "[link][reference]",
+        "[reference]: https://biomejs.dev\n",
+        "[`noConsole`](https://biomejs.dev/linter/rules/no-console)",
+        "<https://biomejs.dev>",
+        "![image](image.png)",
+        "<details>",
+        "</details>",
+        "{",
+        "}",
+        "```js\n",
+        "```js,expect_diagnostic\n",
+        "```json\n",
+        "```\n",
+        "debugger;\n",
+    ];
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(256))]
+
+        #[test]
+        fn random_markdown_does_not_panic(
+            fragments in prop::collection::vec(prop::sample::select(MARKDOWN_FRAGMENTS), 0..32),
+            text in "\\PC{0,32}",
+        ) {
+            let docs = format!("{}{text}", fragments.concat());
+            // Either the page or an error, but never a panic
+            let _ = parse(&docs);
+        }
+    }
+
+    #[test]
+    fn todo_markers_are_warned_and_fail_in_strict_mode() {
+        let (documentation, _) = document(
+            "Disallow debugger statements. TODO: explain why.\n\n```js\n// TODO\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            documentation.warnings,
+            [Warning::new(
+                WarningKind::UnfinishedMarker,
+                "the documentation contains the marker `TODO`: Disallow debugger statements. TODO: explain why."
+            )]
+        );
+
+        let docs = "Disallow `debugger`. FIXME";
+        generate(
+            vec![("suspicious", rule("noDebugger", docs))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let config = DocsGenConfig {
+            strict: true,
+            ..DocsGenConfig::default()
+        };
+        let err = generate(vec![("suspicious", rule("noDebugger", docs))], &config).unwrap_err();
+        assert!(
+            format!("{err:?}").contains("the documentation contains the marker `FIXME`"),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn kind_of_each_source_is_rendered() {
+        let meta = || {
+            rule("noRedeclare", "Disallow variable redeclarations.")
+                .sources(&[
+                    biome_analyze::RuleSource::Eslint("no-redeclare"),
+                    biome_analyze::RuleSource::EslintTypeScript("no-redeclare"),
+                ])
+                .source_kind(RuleSourceKind::SameLogic)
+        };
+        let output_root = generate_with_extra(
+            vec![("suspicious", meta())],
+            r#"{ "rules": { "noRedeclare": { "sourceKinds": { "no-redeclare": "inspired" } } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-redeclare");
+        let source_line = |name: &str| {
+            page.lines()
+                .find(|line| line.ends_with(&format!("<code>{name}</code></a>")))
+                .unwrap_or_else(|| panic!("no line for {name}:\n{page}"))
+                .to_string()
+        };
+        assert!(source_line("no-redeclare").starts_with("- Inspired from: "));
+        assert!(source_line("@typescript-eslint/no-redeclare").starts_with("- Same as: "));
+        assert!(page.contains("while a rule **inspired from** its source"));
+
+        let config = DocsGenConfig {
+            strict: true,
+            ..DocsGenConfig::default()
+        };
+        let err = generate_with_extra(
+            vec![("suspicious", meta())],
+            r#"{ "rules": { "noRedeclare": { "sourceKinds": { "no-console": "same" } } } }"#,
+            &config,
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:?}")
+                .contains("sets the kind of `no-console`, which isn't a source of the rule"),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn number_of_analyzed_examples_is_in_the_index() {
+        let docs = "Disallow `debugger`.\n\n```js,expect_diagnostic\ndebugger;\n```\n\n```js\nconst a = 1;\n```\n\n```js,ignore\ndebugger\n```\n";
+        let config = DocsGenConfig {
+            example_counts: true,
+            ..DocsGenConfig::default()
+        };
+        let output_root =
+            generate(vec![("suspicious", rule("noDebugger", docs))], &config).unwrap();
+        let index = read_index(&output_root);
+        assert!(
+            index.contains("| Rule name | Description | Properties | Examples |\n"),
+            "{index}"
+        );
+        let row = index
+            .lines()
+            .find(|line| line.contains("[noDebugger]"))
+            .unwrap();
+        assert!(row.ends_with(" 2 |"), "{row}");
+
+        let output_root = generate(
+            vec![("suspicious", rule("noDebugger", docs))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(!read_index(&output_root).contains("Examples |"));
+    }
+
+    #[test]
+    fn indented_code_blocks_are_fenced() {
+        let (documentation, content) = document(
+            "Disallow `debugger`.\n\n    // TODO\n    debugger;\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(
+            content.contains("```\n// TODO\ndebugger;\n```\n"),
+            "{content}"
+        );
+        assert!(documentation.warnings.is_empty());
+        assert_eq!(documentation.analyzed_example_count, 0);
+    }
+
+    #[test]
+    fn see_also_section_is_rendered_as_related_rules() {
+        let docs = "Disallow `debugger`.\n\n## Options\n\nNone.\n\n## See also\n\n- [noConsole](rule:noConsole)\n- [useConst](rule:useConst)\n";
+        assert_eq!(see_also_rules(docs), ["noConsole", "useConst"]);
+
+        let output_root = generate(
+            vec![("suspicious", rule("noDebugger", docs))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page = read_page(&output_root, "no-debugger");
+        assert!(page.contains("## Options\n\nNone.\n\n"), "{page}");
+        assert_eq!(page.matches("## See also").count(), 1, "{page}");
+        assert!(
+            page.contains("<li><a href=\"/linter/rules/no-console\"><code>noConsole</code></a></li>\n<li><a href=\"/linter/rules/use-const\"><code>useConst</code></a></li>\n</ul>"),
+            "{page}"
+        );
+    }
+
+    #[test]
+    fn warnings_are_written_to_the_report() {
+        let report = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            warnings_report: Some(report.path().join("warnings.json")),
+            ..DocsGenConfig::default()
+        };
+        generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow `debugger`. TODO").language("yaml"),
+            )],
+            &config,
+        )
+        .unwrap();
+
+        let report: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(report.path().join("warnings.json")).unwrap())
+                .unwrap();
+        assert_eq!(
+            report,
+            serde_json::json!([
+                {
+                    "rule": "noDebugger",
+                    "kind": "unsupported-language",
+                    "message": "the language `yaml` isn't supported",
+                },
+                {
+                    "rule": "noDebugger",
+                    "kind": "unfinished-marker",
+                    "message": "the documentation contains the marker `TODO`: . TODO",
+                },
+            ])
+        );
+
+        // The report of a clean documentation is an empty list, not a
+        // missing file
+        generate(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            &config,
+        )
+        .unwrap();
+        assert_eq!(
+            fs::read_to_string(report.path().join("warnings.json")).unwrap(),
+            "[]"
+        );
+
+        let err =
+            write_warnings_report(&report.path().join("missing/warnings.json"), &[]).unwrap_err();
+        assert!(
+            err.to_string()
+                .starts_with("failed to write the warnings report"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn aggregates_only_leaves_the_pages_untouched() {
+        let output_root = generate(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let page_path = output_root
+            .path()
+            .join("src/content/docs/linter/rules/no-debugger.md");
+        fs::write(&page_path, "untouched").unwrap();
+
+        // The example fails the analysis, so the page can't be generated
+        let docs =
+            "Disallow the `debugger` statement.\n\n```js,expect_diagnostic\nconst a = 1;\n```\n";
+        let config = DocsGenConfig {
+            aggregates_only: true,
+            inline_examples: true,
+            example_counts: true,
+            ..DocsGenConfig::default()
+        };
+        generate_into(
+            vec![("suspicious", rule("noDebugger", docs).recommended(true))],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(&page_path).unwrap(), "untouched");
+        let index = read_index(&output_root);
+        let row = index
+            .lines()
+            .find(|line| line.contains("[noDebugger]"))
+            .unwrap();
+        assert!(
+            row.contains("| Disallow the <code>debugger</code> statement. |"),
+            "{row}"
+        );
+        assert!(row.ends_with(" <code>const a = 1;</code> | 1 |"), "{row}");
+        let recommended = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/RecommendedRules.astro"),
+        )
+        .unwrap();
+        assert!(recommended.contains("noDebugger"), "{recommended}");
+    }
+
+    #[test]
+    fn aggregates_only_rejects_the_combined_html() {
+        let output_root = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            aggregates_only: true,
+            combined_html: Some(output_root.path().join("rules.html")),
+            ..DocsGenConfig::default()
+        };
+        let err = generate_into(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            "{}",
+            &config,
+            output_root.path(),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("--combined-html"), "{err}");
+        assert!(!output_root.path().join("rules.html").exists());
+    }
+
+    #[test]
+    fn module_kind_of_the_example_is_forced() {
+        // The `with` statement is only allowed in scripts
+        let code = "with (a) {}\n";
+        assert!(lint("js", code).is_err());
+        lint("js,script", code).unwrap();
+        lint("script,js", code).unwrap();
+        assert!(lint("cjs,esm", code).is_err());
+
+        let content = parse("```cjs\nwith (a) {}\n```\n\n```cjs,esm\nconst a = 1;\n```\n").unwrap();
+        assert!(content.contains("```cjs\nwith (a) {}\n```\n"), "{content}");
+        assert!(content.contains("```js\nconst a = 1;\n```\n"), "{content}");
+
+        // A forced script is labelled like the block it's equivalent to
+        let content = parse("```js,script\nwith (a) {}\n```\n").unwrap();
+        assert!(content.contains("```cjs\nwith (a) {}\n```\n"), "{content}");
+        let content = parse("```ts,script\nconst a = 1;\n```\n").unwrap();
+        assert!(content.contains("```ts\nconst a = 1;\n```\n"), "{content}");
+
+        let err = CodeBlockTest::from_str("json,esm").err().unwrap();
+        assert!(
+            err.to_string()
+                .contains("can only be used with the code blocks of a language with modules"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn performance_impact_is_rendered() {
+        let report = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            warnings_report: Some(report.path().join("warnings.json")),
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate_with_extra(
+            vec![
+                (
+                    "performance",
+                    rule("noAccumulatingSpread", "Disallow spreading accumulators."),
+                ),
+                ("performance", rule("noDelete", "Disallow `delete`.")),
+            ],
+            r#"{ "rules": { "noAccumulatingSpread": { "performanceImpact": "Spreading makes the loop **quadratic**.\n" } } }"#,
+            &config,
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-accumulating-spread");
+        assert!(page.contains("Performance impact"), "{page}");
+        assert!(
+            page.contains("Spreading makes the loop **quadratic**.\n"),
+            "{page}"
+        );
+        assert!(!read_page(&output_root, "no-delete").contains("Performance impact"));
+
+        let report = fs::read_to_string(report.path().join("warnings.json")).unwrap();
+        assert!(
+            report.contains("\"missing-performance-impact\""),
+            "{report}"
+        );
+        assert_eq!(
+            report.matches("\"rule\": \"noDelete\"").count(),
+            1,
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn email_and_collapsed_links_are_rendered() {
+        let content =
+            parse("Write to <team@biomejs.dev>, see [Biome][].\n\n[Biome]: https://biomejs.dev\n")
+                .unwrap();
+        assert!(
+            content.contains("Write to <team@biomejs.dev>, see [Biome](https://biomejs.dev)."),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn examples_are_labelled_as_valid_or_invalid() {
+        let content = parse(
+            "```js,expect_diagnostic,label\ndebugger;\n```\n\n```js,label\nconst a = 1;\n```\n\n```js\nconst b = 1;\n```\n",
+        )
+        .unwrap();
+        assert!(
+            content.contains("<a id=\"example-1\"></a>\n\n**Invalid**\n\n```js"),
+            "{content}"
+        );
+        assert!(
+            content.contains("<a id=\"example-2\"></a>\n\n**Valid**\n\n```js"),
+            "{content}"
+        );
+        assert!(
+            content.contains("<a id=\"example-3\"></a>\n\n```js"),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn index_larger_than_the_limit_is_rejected() {
+        let rules = || vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))];
+        let output_root = generate(rules(), &DocsGenConfig::default()).unwrap();
+        let size = read_index(&output_root).len();
+
+        let config = DocsGenConfig {
+            max_index_size: Some(size),
+            ..DocsGenConfig::default()
+        };
+        generate(rules(), &config).unwrap();
+
+        let config = DocsGenConfig {
+            max_index_size: Some(size - 1),
+            ..DocsGenConfig::default()
+        };
+        let err = generate(rules(), &config).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "the index of the rules is {size} bytes long, more than the limit of {} bytes, consider splitting it",
+                size - 1
+            )
+        );
+    }
+
+    #[test]
+    fn examples_are_analyzed_with_the_pinned_configuration() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("biome.json");
+        // The severity of the diagnostics follows the level of the rule
+        for (level, severity, other_severity) in [
+            (
+                "error",
+                "<span style=\"color: Tomato;\">✖</span>",
+                "color: Orange;",
+            ),
+            ("warn", "color: Orange;", "✖"),
+        ] {
+            fs::write(
+                &path,
+                format!(
+                    r#"{{ "linter": {{ "rules": {{ "suspicious": {{ "noDebugger": "{level}" }} }} }} }}"#
+                ),
+            )
+            .unwrap();
+            let config = DocsGenConfig {
+                configuration: Some(load_pinned_configuration(path.clone()).unwrap()),
+                ..DocsGenConfig::default()
+            };
+            let (_, content) =
+                document("```js,expect_diagnostic\ndebugger;\n```\n", &config).unwrap();
+            assert!(content.contains(severity), "{level}: {content}");
+            assert!(!content.contains(other_severity), "{level}: {content}");
+        }
+
+        fs::write(&path, r#"{ "linter": "#).unwrap();
+        let err = load_pinned_configuration(path.clone()).unwrap_err();
+        assert!(
+            err.starts_with(&format!("failed to parse {}", path.display())),
+            "{err}"
+        );
+        let missing = directory.path().join("missing.json");
+        let err = load_pinned_configuration(missing.clone()).unwrap_err();
+        assert!(
+            err.starts_with(&format!("failed to read {}", missing.display())),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn lossy_code_block_labels_are_warned() {
+        let (documentation, _) = document(
+            "Disallow `debugger`.\n\n```ts\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(documentation.warnings.is_empty());
+
+        let (documentation, content) = document(
+            "Disallow `debugger`.\n\n```ts,script\nconst a = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(content.contains("```ts\n"), "{content}");
+        assert_eq!(
+            documentation.warnings,
+            [Warning::new(
+                WarningKind::LossyLanguageLabel,
+                "the code block `ts,script` is rendered with the label `ts`, which describes another language or module kind"
+            )]
+        );
+    }
+
+    #[test]
+    fn inspired_sources_are_explained() {
+        use biome_analyze::RuleSource;
+
+        let sources = &[RuleSource::Eslint("no-debugger")];
+        let output_root = generate(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow `debugger`.")
+                        .sources(sources)
+                        .source_kind(RuleSourceKind::Inspired),
+                ),
+                (
+                    "suspicious",
+                    rule("noConsole", "Disallow `console`.")
+                        .sources(sources)
+                        .source_kind(RuleSourceKind::SameLogic),
+                ),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-debugger");
+        assert!(page.contains("- Inspired from: "), "{page}");
+        assert!(
+            page.contains("while a rule **inspired from** its source"),
+            "{page}"
+        );
+        let page = read_page(&output_root, "no-console");
+        assert!(page.contains("- Same as: "), "{page}");
+        assert!(!page.contains("**inspired from**"), "{page}");
+    }
+
+    #[test]
+    fn analyzed_examples_are_reused_across_runs_until_the_options_change() {
+        let docs = "Disallows debugger.\n\n```js,expect_diagnostic\ndebugger;\n```\n";
+        let key = |config: &DocsGenConfig| {
+            snippet_cache_key(
+                "suspicious",
+                "noDebugger",
+                "js,expect_diagnostic",
+                "debugger;\n",
+                None,
+                true,
+                &[],
+                config,
+            )
+        };
+        let config = DocsGenConfig::default();
+        let mut cache = RulePagesCache::default();
+        let mut snippets = cache.take_snippets("noDebugger");
+        let mut content = Vec::new();
+        parse_documentation(
+            "suspicious",
+            "noDebugger",
+            docs,
+            &mut content,
+            true,
+            &[],
+            &["js"],
+            Some(&mut snippets),
+            &config,
+        )
+        .unwrap();
+        cache.insert_snippets("noDebugger", snippets);
+        let directory = tempfile::tempdir().unwrap();
+        let path = cache_file_path(Some(directory.path()));
+        cache.save(&path).unwrap();
+
+        // The same example is found on the next run
+        let mut cache = RulePagesCache::load(&path);
+        let mut snippets = cache.take_snippets("noDebugger");
+        let cached = snippets.get(key(&config)).unwrap();
+        assert!(String::from_utf8(cached.diagnostics)
+            .unwrap()
+            .contains("debugger"));
+        cache.insert_snippets("noDebugger", snippets);
+
+        // Changing the options of the rule analyzes the example again
+        let pinned = DocsGenConfig {
+            configuration: Some(
+                serde_json::from_value(serde_json::json!({
+                    "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+                }))
+                .unwrap(),
+            ),
+            ..DocsGenConfig::default()
+        };
+        assert_ne!(key(&config), key(&pinned));
+        let mut snippets = cache.take_snippets("noDebugger");
+        assert!(snippets.get(key(&pinned)).is_none());
+
+        // The options that change the pages and the renderer are part of the
+        // key, the ones that only check or report the pages aren't
+        let other_locale = DocsGenConfig {
+            locale: Locale::Fr,
+            ..DocsGenConfig::default()
+        };
+        assert_ne!(key(&config), key(&other_locale));
+        let other_checks = DocsGenConfig {
+            cache_dir: Some(PathBuf::from("cache")),
+            incremental: true,
+            strict: true,
+            validate_only: true,
+            warnings_report: Some(PathBuf::from("warnings.json")),
+            combined_html: Some(PathBuf::from("rules.html")),
+            max_index_size: Some(1024),
+            ..DocsGenConfig::default()
+        };
+        assert_eq!(key(&config), key(&other_checks));
+        let other_renderer = DocsGenConfig {
+            renderer: Renderer::new(RecordingRenderer::default()),
+            ..DocsGenConfig::default()
+        };
+        assert_ne!(key(&config), key(&other_renderer));
+        assert_eq!(key(&config), key(&DocsGenConfig::default()));
+    }
+
+    #[test]
+    fn page_key_follows_the_extra_metadata() {
+        let meta = rule("noDebugger", "Disallow `debugger`.");
+        let key = |extra: Option<&RuleExtraMetadata>| {
+            rule_page_cache_key(
+                "suspicious",
+                &meta,
+                &["js"],
+                false,
+                extra,
+                &[],
+                &DocsGenConfig::default(),
+            )
+        };
+        let extra = |json: &str| serde_json::from_str::<RuleExtraMetadata>(json).unwrap();
+
+        let react = extra(r#"{ "frameworks": ["react"] }"#);
+        assert_eq!(
+            key(Some(&react)),
+            key(Some(&extra(r#"{ "frameworks": ["react"] }"#)))
+        );
+        assert_ne!(
+            key(Some(&react)),
+            key(Some(&extra(r#"{ "frameworks": ["vue"] }"#)))
+        );
+        assert_ne!(key(None), key(Some(&RuleExtraMetadata::default())));
+        assert_ne!(
+            key(None),
+            rule_page_cache_key(
+                "suspicious",
+                &rule("noDebugger", "Disallow `debugger`.").fix_kind(FixKind::Safe),
+                &["js"],
+                false,
+                None,
+                &[],
+                &DocsGenConfig::default(),
+            )
+        );
+    }
+
+    #[test]
+    fn forced_severity_is_rendered() {
+        let render = |info_string: &str, config: &DocsGenConfig| {
+            let test = CodeBlockTest::from_str(info_string).unwrap();
+            let mut content = Vec::new();
+            assert_lint(
+                "suspicious",
+                "noDebugger",
+                &test,
+                "debugger;\n",
+                &mut content,
+                true,
+                config,
+            )
+            .unwrap();
+            String::from_utf8(content).unwrap()
+        };
+        // The rule is a warning in the pinned configuration
+        let warning = DocsGenConfig {
+            diagnostic_backend: DiagnosticBackend::Text,
+            configuration: Some(
+                serde_json::from_value(serde_json::json!({
+                    "linter": { "rules": { "suspicious": { "noDebugger": "warn" } } }
+                }))
+                .unwrap(),
+            ),
+            ..DocsGenConfig::default()
+        };
+        let error = DocsGenConfig {
+            diagnostic_backend: DiagnosticBackend::Text,
+            ..DocsGenConfig::default()
+        };
+
+        let forced = render("js,expect_diagnostic,severity=error", &warning);
+        assert_ne!(forced, render("js,expect_diagnostic", &warning));
+        assert_eq!(forced, render("js,expect_diagnostic", &error));
+
+        assert!(CodeBlockTest::from_str("js,severity=fatal").is_err());
+    }
+
+    #[test]
+    fn class_of_the_diagnostics_is_configurable() {
+        let docs = "```js,expect_diagnostic\ndebugger;\n```\n";
+        let (_, content) = document(docs, &DocsGenConfig::default()).unwrap();
+        assert!(
+            content.contains(r#"<pre class="language-text"><code class="language-text">"#),
+            "{content}"
+        );
+
+        let config = DocsGenConfig {
+            diagnostic_class: "diagnostic".to_string(),
+            ..DocsGenConfig::default()
+        };
+        let (_, content) = document(docs, &config).unwrap();
+        assert!(
+            content.contains(r#"<pre class="diagnostic"><code class="diagnostic">"#),
+            "{content}"
+        );
+        assert!(!content.contains("language-text"), "{content}");
+    }
+
+    #[test]
+    fn rules_of_each_group_are_split_by_recommendation() {
+        let output_root = generate(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow the use of `debugger`.").recommended(true),
+                ),
+                (
+                    "suspicious",
+                    rule("noConsole", "Disallow the use of `console`."),
+                ),
+                (
+                    "style",
+                    rule("useConst", "Require `const` declarations.").recommended(true),
+                ),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let table = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/RecommendedRulesByGroup.astro"),
+        )
+        .unwrap();
+        assert!(
+            table.contains(
+                "<thead><tr><th>Group</th><th>Recommended</th><th>Opt-in</th></tr></thead>"
+            ),
+            "{table}"
+        );
+        assert!(
+            table.contains("<tr><td>Suspicious</td><td><a href='/linter/rules/no-debugger'>noDebugger</a></td><td><a href='/linter/rules/no-console'>noConsole</a></td></tr>"),
+            "{table}"
+        );
+        assert!(
+            table.contains("<tr><td>Style</td><td><a href='/linter/rules/use-const'>useConst</a></td><td></td></tr>"),
+            "{table}"
+        );
+        assert!(read_index(&output_root).contains("<RecommendedRulesByGroup />"));
+    }
+
+    #[test]
+    fn recommended_rules_are_listed_once() {
+        let expected = BTreeSet::from(["noDebugger", "useConst"]);
+        let list = "\t<li><a href='/linter/rules/no-debugger'>noDebugger</a></li>\n\
+            \t<li><a href='/linter/rules/use-const'>useConst</a></li>\n";
+        validate_recommended_rules(list, &expected).unwrap();
+
+        let duplicated =
+            format!("{list}\t<li><a href='/linter/rules/use-const'>useConst</a></li>\n");
+        let err = validate_recommended_rules(&duplicated, &expected).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the rule useConst is listed more than once in the recommended rules"
+        );
+
+        let err = validate_recommended_rules(list, &BTreeSet::from(["noDebugger"])).unwrap_err();
+        assert!(err.to_string().contains("aren't recommended"), "{err}");
+    }
+
+    #[test]
+    fn recommended_nursery_rules_are_not_expected_in_the_list() {
+        let output_root = generate(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow `debugger`.").recommended(true),
+                ),
+                (
+                    "nursery",
+                    rule("noConsole", "Disallow `console`.").recommended(true),
+                ),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let list = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/RecommendedRules.astro"),
+        )
+        .unwrap();
+        assert!(list.contains(">noDebugger</a>"), "{list}");
+        assert!(!list.contains(">noConsole</a>"), "{list}");
+    }
+
+    #[test]
+    fn example_is_rendered_once_per_options() {
+        let (_, content) = document_rule(
+            "suspicious",
+            "noConsole",
+            "Disallow `console`.\n\n\
+            ```json,options=default\n{}\n```\n\n\
+            ```json,options=allowLog\n{ \"allow\": [\"log\"] }\n```\n\n\
+            ```js,use_options=default,use_options=allowLog,expect_diagnostic=default\nconsole.log(\"a\");\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let (default, allow_log) = content.split_once("With the options `allowLog`:").unwrap();
+        assert!(default.contains("With the options `default`:"), "{content}");
+        assert!(
+            default.contains("<pre class=\"language-text\">"),
+            "{content}"
+        );
+        assert!(!allow_log.contains("<pre"), "{content}");
+        assert!(allow_log.contains("No diagnostic is emitted."), "{content}");
+    }
+
+    #[test]
+    fn legend_links_to_the_recommended_rules() {
+        let output_root = generate(
+            vec![(
+                "suspicious",
+                rule("noDebugger", "Disallow `debugger`.").recommended(true),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        let index = read_index(&output_root);
+        let icon = Renderer::default().icon("approve-check-circle", "This rule is recommended");
+        assert!(
+            index.contains(&format!(
+                "- The icon [{icon}](#recommended-rules) indicates that the rule is part of the recommended rules."
+            )),
+            "{index}"
+        );
+        assert!(index.contains("\n## Recommended rules\n"), "{index}");
+    }
+
+    #[test]
+    fn uninformative_summaries_are_warned() {
+        let (documentation, _) = document("Debugger.\n", &DocsGenConfig::default()).unwrap();
+        assert_eq!(
+            documentation.warnings,
+            [Warning::new(
+                WarningKind::UninformativeSummary,
+                "the summary `Debugger.` doesn't describe what the rule reports"
+            )]
+        );
+
+        assert!(is_uninformative_summary("No debugger.", "noDebugger"));
+        assert!(is_uninformative_summary("", "noDebugger"));
+        assert!(!is_uninformative_summary(
+            "Disallow the use of `debugger`.",
+            "noDebugger"
+        ));
+    }
+
+    #[test]
+    fn title_of_the_code_action_is_checked() {
+        lint(
+            "js,expect_diagnostic,expect_action=\"Remove debugger statement\"",
+            "debugger;\n",
+        )
+        .unwrap();
+
+        let err = lint(
+            "js,expect_diagnostic,expect_action=\"Remove the statement\"",
+            "debugger;\n",
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                r#"expects a code action titled `Remove the statement`, the titles of its code actions were: ["Remove debugger statement"]"#
+            ),
+            "{err}"
+        );
+
+        assert!(CodeBlockTest::from_str("js,expect_action=\"Remove debugger statement\"").is_err());
+        assert!(info_string_tokens("js,expect_action=\"Remove").is_err());
+        assert_eq!(
+            info_string_tokens("js, expect_action=\"Remove it, now\"").unwrap(),
+            ["js", "expect_action=Remove it, now"]
+        );
+    }
+
+    #[test]
+    fn french_pages_have_no_english_text() {
+        let config = DocsGenConfig {
+            locale: Locale::Fr,
+            ..DocsGenConfig::default()
+        };
+        let stable = rule(
+            "useConst",
+            "Require `const` declarations.\n\n## Why is this bad?\n\nThe variable is never reassigned.\n\n```js,expect_diagnostic,label,output\nlet a = 1;\n```\n\n```js,label\nconst a = 1;\n```\n\n## See also\n\n- [noDebugger](rule:noDebugger)\n",
+        )
+        .recommended(true)
+        .fix_kind(FixKind::Safe)
+        .sources(&[biome_analyze::RuleSource::Eslint("prefer-const")])
+        .source_kind(RuleSourceKind::Inspired);
+        let nursery = rule("noDebugger", "Disallow the use of `debugger`.");
+        let output_root = generate(vec![("style", stable), ("nursery", nursery)], &config).unwrap();
+
+        let en = Locale::En.messages();
+        let fr = Locale::Fr.messages();
+        for page in ["use-const", "no-debugger"] {
+            let content = read_page(&output_root, page);
+            for (english, french) in en.strings().into_iter().zip(fr.strings()) {
+                if english == french {
+                    continue;
+                }
+                // The text between the placeholders, long enough not to be
+                // part of a French word
+                let fragments = english
+                    .split(['{', '}'])
+                    .step_by(2)
+                    .map(str::trim)
+                    .filter(|fragment| fragment.chars().filter(|c| c.is_alphabetic()).count() > 3);
+                for fragment in fragments {
+                    assert!(
+                        !content.contains(fragment),
+                        "the page {page} contains the English text `{fragment}`:\n{content}"
+                    );
+                }
+            }
+        }
+        assert!(read_index(&output_root).contains(fr.rules_introduction));
+        let recommended_rules_by_group = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/RecommendedRulesByGroup.astro"),
+        )
+        .unwrap();
+        assert!(
+            recommended_rules_by_group.contains(&format!(
+                "<th>{}</th><th>{}</th><th>{}</th>",
+                fr.group_column, fr.recommended_column, fr.opt_in_column
+            )),
+            "{recommended_rules_by_group}"
+        );
+    }
+
+    #[test]
+    fn problems_of_the_rules_are_translated() {
+        let warnings = [("noDebugger", "the summary is too short")];
+        assert_eq!(
+            rule_problems(
+                Locale::Fr.messages().rule_warnings,
+                warnings.into_iter(),
+                Locale::Fr.messages()
+            ),
+            "La documentation des règles suivantes a des avertissements :\n- noDebugger : the summary is too short\n"
+        );
+        assert_eq!(
+            rule_problems(
+                Locale::En.messages().ignored_examples,
+                warnings.into_iter(),
+                Locale::En.messages()
+            ),
+            "The following examples are ignored:\n- noDebugger: the summary is too short\n"
+        );
+    }
+
+    #[test]
+    fn unsafe_fix_rationale_is_rendered() {
+        let report = tempfile::tempdir().unwrap();
+        let config = DocsGenConfig {
+            warnings_report: Some(report.path().join("warnings.json")),
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate_with_extra(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow the use of `debugger`.")
+                        .fix_kind(FixKind::Unsafe),
+                ),
+                (
+                    "suspicious",
+                    rule("noConsole", "Disallow the use of `console`.")
+                        .fix_kind(FixKind::Unsafe),
+                ),
+            ],
+            r#"{ "rules": { "noDebugger": { "unsafeFixRationale": "Removing the statement\nchanges the behavior of the debugger.\n" } } }"#,
+            &config,
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-debugger");
+        assert!(
+            page.contains(
+                "- This rule has an **unsafe** fix.\n  Removing the statement\n  changes the behavior of the debugger.\n"
+            ),
+            "{page}"
+        );
+
+        let report = fs::read_to_string(report.path().join("warnings.json")).unwrap();
+        assert!(
+            report.contains("\"missing-unsafe-fix-rationale\""),
+            "{report}"
+        );
+        assert_eq!(
+            report.matches("\"rule\": \"noConsole\"").count(),
+            1,
+            "{report}"
+        );
+        assert!(!report.contains("\"rule\": \"noDebugger\""), "{report}");
+
+        // Most rules don't explain their unsafe fix yet, so it's only reported
+        let config = DocsGenConfig {
+            strict: true,
+            ..DocsGenConfig::default()
+        };
+        generate(
+            vec![(
+                "suspicious",
+                rule("noConsole", "Disallow the use of `console`.").fix_kind(FixKind::Unsafe),
+            )],
+            &config,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn examples_are_analyzed_as_another_extension() {
+        let code = "let value: number = 1;\n";
+        assert!(lint("js", code).is_err());
+        lint("js,as=ts", code).unwrap();
+
+        let (_, content) = document(
+            "Disallow `debugger`.\n\n```js,as=ts\nlet value: number = 1;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(content.contains("```js"), "{content}");
+        assert!(!content.contains("```ts"), "{content}");
+
+        let err = CodeBlockTest::from_str("js,as=graphql").unwrap_err();
+        assert!(
+            err.to_string().contains("unknown extension `graphql`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn number_of_code_actions_is_checked() {
+        lint("js,expect_diagnostic,expect_fixes=1", "debugger;\n").unwrap();
+
+        let err = lint("js,expect_diagnostic,expect_fixes=2", "debugger;\n").unwrap_err();
+        assert!(
+            err.to_string().contains(
+                r#"expects 2 code actions, but its diagnostic has 1: ["Remove debugger statement"]"#
+            ),
+            "{err}"
+        );
+
+        assert!(CodeBlockTest::from_str("js,expect_fixes=1").is_err());
+        let err = CodeBlockTest::from_str("js,expect_diagnostic,expect_fixes=one").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("the number of fixes `one` isn't a number"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn legend_describes_the_icons_of_the_rules() {
+        let renderer = Renderer::default();
+        let index = format!(
+            "- The icon {} indicates a CSS rule.\n| <span class=\"rule-group\" data-group=\"suspicious\">[noDebugger](/linter/rules/no-debugger)</span> | Disallow `debugger`. | {} |\n",
+            renderer.icon("seti:css", "CSS rule"),
+            renderer.icon("seti:javascript", "JavaScript rule"),
+        );
+        let used = index_icons(&index, &renderer).unwrap();
+        assert_eq!(used, BTreeSet::from(["seti:javascript"]));
+        validate_legend(&used, &BTreeSet::from(["seti:javascript", "seti:css"])).unwrap();
+
+        let err = validate_legend(&used, &BTreeSet::new()).unwrap_err();
+        assert!(
+            err.to_string().contains(
+                r#"the rules of the index use the icons ["seti:javascript"], which the legend doesn't describe"#
+            ),
+            "{err}"
+        );
+
+        let output_root = generate(
+            vec![("suspicious", rule("noDebugger", "Disallow `debugger`."))],
+            &DocsGenConfig {
+                validate_icons: true,
+                ..DocsGenConfig::default()
+            },
+        )
+        .unwrap();
+        let legend: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(
+                output_root
+                    .path()
+                    .join("src/components/generated/RulesLegend.json"),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let icons = legend
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["icon"].as_str().unwrap())
+            .collect::<Vec<_>>();
+        // The rule isn't recommended, has no fix and only applies to JavaScript
+        assert_eq!(icons, ["seti:javascript"]);
+        let index = read_index(&output_root);
+        assert!(!index.contains("seti:css"), "{index}");
+        assert!(!index.contains("seti:typescript"), "{index}");
+    }
+
+    #[test]
+    fn legend_of_a_subset_describes_its_languages() {
+        let config = DocsGenConfig {
+            languages: vec![RegistryLanguage("css")],
+            validate_icons: true,
+            ..DocsGenConfig::default()
+        };
+        // Only the CSS registry is visited with `--languages css`, the icons
+        // of the other languages would be orphans of the legend
+        let output_root = generate(
+            vec![(
+                "correctness",
+                RuleMetadata::new("1.0.0", "noUnknownUnit", "Disallow unknown CSS units.")
+                    .language("css")
+                    .recommended(true),
+            )],
+            &config,
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-unknown-unit");
+        assert!(page.contains("languages: [css]"), "{page}");
+    }
+
+    #[test]
+    fn rules_of_the_highlighted_version_are_badged() {
+        let config = DocsGenConfig {
+            highlight_version: Some(Version::new(1, 1, 0)),
+            ..DocsGenConfig::default()
+        };
+        let output_root = generate(
+            vec![
+                ("suspicious", rule("noDebugger", "Disallow `debugger`.")),
+                (
+                    "suspicious",
+                    RuleMetadata::new("1.1.0", "noConsole", "Disallow `console`.").language("js"),
+                ),
+            ],
+            &config,
+        )
+        .unwrap();
+
+        let index = read_index(&output_root);
+        assert!(
+            index.contains(
+                "[noConsole](/linter/rules/no-console)</span> <span class=\"new-rule-badge\">New</span> |"
+            ),
+            "{index}"
+        );
+        assert!(
+            index.contains("[noDebugger](/linter/rules/no-debugger)</span> |"),
+            "{index}"
+        );
+    }
+
+    #[test]
+    fn end_of_the_docs_is_followed_by_one_blank_line() {
+        for (docs, end) in [
+            (
+                "Disallow `debugger`.\n\n- Use a breakpoint.\n- Use a log.\n",
+                "- Use a log.\n\n## Related links",
+            ),
+            (
+                "Disallow `debugger`.\n\n```js,ignore\ndebugger;\n```\n",
+                "debugger;\n```\n\n## Related links",
+            ),
+            (
+                "Disallow `debugger`.\n",
+                "Disallow `debugger`.\n\n## Related links",
+            ),
+        ] {
+            let output_root = generate(
+                vec![("suspicious", rule("noDebugger", docs))],
+                &DocsGenConfig::default(),
+            )
+            .unwrap();
+            let page = read_page(&output_root, "no-debugger");
+            assert!(page.contains(end), "{page}");
+        }
+    }
+
+    #[test]
+    fn formatter_overlap_is_noted() {
+        let output_root = generate_with_extra(
+            vec![
+                ("style", rule("useSingleQuotes", "Enforce single quotes.")),
+                ("style", rule("useConst", "Require `const` declarations.")),
+            ],
+            r#"{ "rules": { "useSingleQuotes": { "formatterOverlap": true } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let note = ":::note\nThis rule is unnecessary if you use the formatter of Biome, which already formats the code it reports.\n:::\n";
+        let page = read_page(&output_root, "use-single-quotes");
+        assert!(page.contains(note), "{page}");
+        assert!(!read_page(&output_root, "use-const").contains(note));
+    }
+
+    #[test]
+    fn example_in_another_language_is_rejected() {
+        let err = parse(
+            "Disallow the use of `debugger`.\n\n```css,expect_diagnostic\na { color: red; }\n```\n",
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("can't be analyzed, the rule only runs on the languages js"),
+            "unexpected error: {err}"
+        );
+        // The examples that aren't analyzed can be in any language
+        parse("Disallow the use of `debugger`.\n\n```css,ignore\na { color: red; }\n```\n")
+            .unwrap();
+    }
+
+    #[test]
+    fn groups_of_the_index_are_tagged() {
+        let output_root = generate(
+            vec![
+                ("suspicious", rule("noDebugger", "Disallow `debugger`.")),
+                ("style", rule("useConst", "Require `const` declarations.")),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let index = read_index(&output_root);
+        assert!(
+            index.contains("\n## <span class=\"rule-group\" data-group=\"style\">Style</span>\n"),
+            "{index}"
+        );
+        assert!(
+            index.contains(
+                "| <span class=\"rule-group\" data-group=\"suspicious\">[noDebugger](/linter/rules/no-debugger)</span> |"
+            ),
+            "{index}"
+        );
+        assert_eq!(index.lines().filter(|line| is_rule_row(line)).count(), 2);
+    }
+
+    #[test]
+    fn security_group_is_tagged_and_colored() {
+        let output_root = generate(
+            vec![(
+                "security",
+                rule("noGlobalEval", "Disallow the use of global `eval()`."),
+            )],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let index = read_index(&output_root);
+        assert!(
+            index.contains(
+                "\n## <span class=\"rule-group\" data-group=\"security\">Security</span>\n"
+            ),
+            "{index}"
+        );
+        assert!(
+            index.contains(
+                "| <span class=\"rule-group\" data-group=\"security\">[noGlobalEval](/linter/rules/no-global-eval)</span> |"
+            ),
+            "{index}"
+        );
+        // The stylesheet gives the group its color
+        let styles = fs::read_to_string(project_root().join("src/styles/_rules.scss")).unwrap();
+        assert!(styles.contains("&[data-group=\"security\"] {"), "{styles}");
+    }
+
+    #[test]
+    fn suppressed_range_hides_only_its_code() {
+        let outcome = lint(
+            "js,expect_diagnostic",
+            "debugger;\n// biome-ignore-start lint/suspicious/noDebugger: the tests\ndebugger;\n// biome-ignore-end lint/suspicious/noDebugger: the tests\n",
+        )
+        .unwrap();
+        assert_eq!(outcome.messages.len(), 1);
+    }
+
+    #[test]
+    fn diagnostic_inside_suppressed_range_is_rejected() {
+        // The range suppresses another rule
+        let err = lint(
+            "js,expect_diagnostic",
+            "debugger;\n// biome-ignore-start lint/suspicious/noConsole: the tests\ndebugger;\n// biome-ignore-end lint/suspicious/noConsole: the tests\n",
+        )
+        .err()
+        .unwrap();
+        assert!(
+            err.to_string().contains("inside the suppressed range"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn suppressed_range_requires_reported_code() {
+        let err = lint(
+            "js,expect_diagnostic",
+            "// biome-ignore-start lint/suspicious/noDebugger: the tests\ndebugger;\n// biome-ignore-end lint/suspicious/noDebugger: the tests\n",
+        )
+        .err()
+        .unwrap();
+        assert!(
+            err.to_string()
+                .contains("doesn't have a diagnostic outside of the range"),
+            "unexpected error: {err}"
+        );
+
+        let err = lint(
+            "js",
+            "// biome-ignore-start lint/suspicious/noDebugger: the tests\ndebugger;\n// biome-ignore-end lint/suspicious/noDebugger: the tests\n",
+        )
+        .err()
+        .unwrap();
+        assert!(
+            err.to_string()
+                .contains("must be marked with `expect_diagnostic`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn suppression_ranges_are_read_from_comments() {
+        let code = "const start = \"biome-ignore-start\";\n/* biome-ignore-start lint: a */\ndebugger;\n  // biome-ignore-end lint: a\ndebugger;\n";
+        let start = code.find("/*").unwrap();
+        let end = code.rfind("debugger").unwrap();
+        assert_eq!(suppression_ranges(code).unwrap(), [start..end]);
+
+        let err = suppression_ranges("debugger;\n// biome-ignore-end lint: a\n").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`biome-ignore-end` comment without a matching `biome-ignore-start`"),
+            "unexpected error: {err}"
+        );
+        let err = suppression_ranges("// biome-ignore-start lint: a\ndebugger;\n").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("`biome-ignore-start` comment without a matching `biome-ignore-end`"),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn skipped_heading_levels_are_warned() {
+        let (documentation, _) = document(
+            "Disallow `debugger`.\n\n## Options\n\n### `allow`\n\n## Examples\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(documentation.warnings.is_empty());
+
+        let (documentation, _) = document(
+            "Disallow `debugger`.\n\n### Options\n\n## Examples\n\n#### `allow`\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            documentation.warnings,
+            [
+                Warning::new(
+                    WarningKind::SkippedHeadingLevel,
+                    "the documentation has a heading of level 3, but the deepest level expected at this point is 2"
+                ),
+                Warning::new(
+                    WarningKind::SkippedHeadingLevel,
+                    "the documentation has a heading of level 4, but the deepest level expected at this point is 3"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_attributes_keep_the_language_of_the_block() {
+        let test = CodeBlockTest::from_str("js unknownflag").unwrap();
+        assert!(
+            matches!(&test.block_type, BlockType::Code(source) if source.language.name == "js")
+        );
+        assert!(!test.ignore);
+        assert_eq!(test.unknown_attributes, ["unknownflag"]);
+
+        // The block is still analyzed, so its unexpected diagnostic fails
+        assert!(document(
+            "Disallow `debugger`.\n\n```js unknownflag\ndebugger;\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .is_err());
+
+        let (documentation, content) = document(
+            "Disallow `debugger`.\n\n```js,expect_diagnostic,unknownflag\ndebugger;\n```\n\n```js\nconsole.log(1);\n```\n",
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+        assert!(content.contains("```js"), "{content}");
+        assert_eq!(
+            documentation.warnings,
+            [Warning::new(
+                WarningKind::UnknownAttribute,
+                "the code block `js,expect_diagnostic,unknownflag` of noDebugger has the unknown attribute `unknownflag`, the block is still analyzed"
+            )]
+        );
+    }
+
+    #[test]
+    fn recommended_configuration_lists_the_recommended_rules() {
+        let output_root = generate(
+            vec![
+                (
+                    "suspicious",
+                    rule("noDebugger", "Disallow `debugger`.").recommended(true),
+                ),
+                ("suspicious", rule("noConsole", "Disallow `console`.")),
+                (
+                    "complexity",
+                    rule("noUselessCatch", "Disallow useless `catch`."),
+                ),
+                (
+                    "style",
+                    rule("useConst", "Require `const` declarations.").recommended(true),
+                ),
+            ],
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let configuration = fs::read_to_string(
+            output_root
+                .path()
+                .join("src/components/generated/RecommendedConfiguration.json"),
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::from_str::<Value>(&configuration).unwrap(),
+            serde_json::json!({
+                "linter": {
+                    "rules": {
+                        "recommended": true,
+                        "style": { "useConst": "on" },
+                        "suspicious": { "noDebugger": "on" },
+                    }
+                }
+            })
+        );
+        let index = read_index(&output_root);
+        assert!(
+            index.contains(&format!(
+                "The recommended preset is equivalent to the following configuration:\n\n```json\n{configuration}\n```\n"
+            )),
+            "{index}"
+        );
+    }
+
+    #[test]
+    fn superseding_rules_are_linked_from_both_pages() {
+        let rules = || {
+            vec![
+                (
+                    "suspicious",
+                    rule("noConsoleLog", "Disallow `console.log`."),
+                ),
+                ("suspicious", rule("noConsole", "Disallow `console`.")),
+            ]
+        };
+        let output_root = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noConsole": { "supersedes": ["noConsoleLog"] } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap();
+
+        let page = read_page(&output_root, "no-console");
+        assert!(
+            page.contains("Supersedes: \n- [`noConsoleLog`](/linter/rules/no-console-log)\n"),
+            "{page}"
+        );
+        let page = read_page(&output_root, "no-console-log");
+        assert!(
+            page.contains("Superseded by: \n- [`noConsole`](/linter/rules/no-console)\n"),
+            "{page}"
+        );
+
+        let err = generate_with_extra(
+            rules(),
+            r#"{ "rules": { "noConsole": { "supersedes": ["noPrint"] } } }"#,
+            &DocsGenConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains(
+                "`supersedes` in `codegen/extra-metadata.json` references rules that don't exist: `noConsole` supersedes `noPrint`"
+            ),
+            "{err}"
+        );
+    }
+}
@@ -0,0 +1,582 @@
+use std::str::FromStr;
+
+/// The language of the UI strings of the rule pages, the text around the
+/// documentation of the rules
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Locale {
+    #[default]
+    En,
+    Fr,
+}
+
+impl Locale {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::Fr => "fr",
+        }
+    }
+
+    /// Returns the UI strings of the locale
+    pub(crate) fn messages(self) -> &'static Messages {
+        match self {
+            Self::En => &EN,
+            Self::Fr => &FR,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "en" => Ok(Self::En),
+            "fr" => Ok(Self::Fr),
+            _ => Err(format!("unknown locale `{input}`, expected `en` or `fr`")),
+        }
+    }
+}
+
+/// The UI strings of the rule pages and of their index. The strings
+/// containing Markdown keep its formatting when they're translated, and their
+/// placeholders, like `{version}`, are replaced with [fill].
+#[derive(Debug)]
+pub(crate) struct Messages {
+    pub(crate) recommended: &'static str,
+    pub(crate) safe_fix: &'static str,
+    pub(crate) unsafe_fix: &'static str,
+    pub(crate) applies_to: &'static str,
+    pub(crate) sources: &'static str,
+    pub(crate) inspired_from: &'static str,
+    pub(crate) same_as: &'static str,
+    pub(crate) supersedes: &'static str,
+    pub(crate) superseded_by: &'static str,
+    pub(crate) changes: &'static str,
+    pub(crate) diagnostic_example: &'static str,
+    pub(crate) related_links: &'static str,
+    pub(crate) disable_rule: &'static str,
+    pub(crate) rule_options: &'static str,
+    // The rule pages
+    pub(crate) since_version: &'static str,
+    pub(crate) not_released: &'static str,
+    pub(crate) diagnostic_category: &'static str,
+    pub(crate) applied_to_files: &'static str,
+    pub(crate) and: &'static str,
+    pub(crate) js_files: &'static str,
+    pub(crate) jsx_files: &'static str,
+    pub(crate) ts_files: &'static str,
+    pub(crate) by_default: &'static str,
+    pub(crate) option_default: &'static str,
+    pub(crate) nursery_group: &'static str,
+    pub(crate) nursery_nightly_error: &'static str,
+    pub(crate) nursery_nightly_warning: &'static str,
+    pub(crate) nursery_stable: &'static str,
+    pub(crate) source_kinds_explanation: &'static str,
+    pub(crate) performance_impact: &'static str,
+    pub(crate) formatter_overlap: &'static str,
+    pub(crate) unreleased: &'static str,
+    pub(crate) option_values: &'static str,
+    pub(crate) value: &'static str,
+    pub(crate) description: &'static str,
+    pub(crate) see_also: &'static str,
+    pub(crate) options_schema: &'static str,
+    pub(crate) rationale: &'static str,
+    // The examples of the rule pages
+    pub(crate) invalid: &'static str,
+    pub(crate) valid: &'static str,
+    pub(crate) with_options: &'static str,
+    pub(crate) output: &'static str,
+    pub(crate) inline_output: &'static str,
+    pub(crate) full_diagnostic: &'static str,
+    pub(crate) no_diagnostic: &'static str,
+    pub(crate) more_diagnostics: &'static str,
+    pub(crate) ignored_examples: &'static str,
+    pub(crate) rule_warnings: &'static str,
+    pub(crate) rule_problem: &'static str,
+    // The index of the rules
+    pub(crate) rules: &'static str,
+    pub(crate) rules_description: &'static str,
+    pub(crate) rules_introduction: &'static str,
+    pub(crate) legend_icon: &'static str,
+    pub(crate) recommended_label: &'static str,
+    pub(crate) recommended_legend: &'static str,
+    pub(crate) safe_fix_label: &'static str,
+    pub(crate) safe_fix_legend: &'static str,
+    pub(crate) unsafe_fix_label: &'static str,
+    pub(crate) unsafe_fix_legend: &'static str,
+    pub(crate) js_label: &'static str,
+    pub(crate) js_legend: &'static str,
+    pub(crate) jsx_label: &'static str,
+    pub(crate) ts_label: &'static str,
+    pub(crate) ts_legend: &'static str,
+    pub(crate) json_label: &'static str,
+    pub(crate) json_legend: &'static str,
+    pub(crate) css_label: &'static str,
+    pub(crate) css_legend: &'static str,
+    pub(crate) nightly_label: &'static str,
+    pub(crate) nightly_legend: &'static str,
+    pub(crate) rule_name: &'static str,
+    pub(crate) properties: &'static str,
+    pub(crate) example: &'static str,
+    pub(crate) examples: &'static str,
+    pub(crate) unreleased_badge: &'static str,
+    pub(crate) new_badge: &'static str,
+    pub(crate) recommended_rules: &'static str,
+    pub(crate) recommended_rules_list: &'static str,
+    pub(crate) recommended_rules_by_group: &'static str,
+    pub(crate) group_column: &'static str,
+    pub(crate) recommended_column: &'static str,
+    pub(crate) opt_in_column: &'static str,
+    pub(crate) recommended_configuration: &'static str,
+    pub(crate) analyzer_version: &'static str,
+    // The page of the fixable rules
+    pub(crate) fixable_rules: &'static str,
+    pub(crate) fixable_rules_description: &'static str,
+    pub(crate) fixable_rules_introduction: &'static str,
+    pub(crate) biome_rule_column: &'static str,
+    pub(crate) fix_column: &'static str,
+    pub(crate) safe_fix_value: &'static str,
+    pub(crate) unsafe_fix_value: &'static str,
+}
+
+/// Replaces the placeholders of the message, e.g. `{version}`, with their values
+pub(crate) fn fill(message: &str, values: &[(&str, &str)]) -> String {
+    values
+        .iter()
+        .fold(message.to_string(), |message, (placeholder, value)| {
+            message.replace(&format!("{{{placeholder}}}"), value)
+        })
+}
+
+const EN: Messages = Messages {
+    recommended:
+        "This rule is recommended by Biome. A diagnostic error will appear when linting your code.",
+    safe_fix: "This rule has a **safe** fix.",
+    unsafe_fix: "This rule has an **unsafe** fix.",
+    applies_to: "Applies to:",
+    sources: "Sources:",
+    inspired_from: "Inspired from:",
+    same_as: "Same as:",
+    supersedes: "Supersedes:",
+    superseded_by: "Superseded by:",
+    changes: "Changes:",
+    diagnostic_example: "Example of a diagnostic message emitted by this rule:",
+    related_links: "Related links",
+    disable_rule: "Disable a rule",
+    rule_options: "Rule options",
+    since_version: "(since v{version})",
+    not_released: "(not released)",
+    diagnostic_category: "Diagnostic Category:",
+    applied_to_files: "This rule is applied to {languages} files.",
+    and: " and ",
+    js_files: "JavaScript and super languages",
+    jsx_files: "JSX and TSX",
+    ts_files: "TypeScript and TSX",
+    by_default: "By default, {options}.",
+    option_default: "`{option}` is `{default}`",
+    nursery_group: "This rule is part of the [nursery](/linter/rules/#nursery) group.",
+    nursery_nightly_error: "It's enabled by default on nightly builds, where its diagnostics are emitted as **errors**.",
+    nursery_nightly_warning: "It's enabled by default on nightly builds, where its diagnostics are emitted as **warnings**.",
+    nursery_stable: "It requires an explicit opt-in via configuration on stable versions.",
+    source_kinds_explanation: "A rule that is the **same as** its source reports the same code, while a rule **inspired from** its source may behave differently, for example by reporting more or less code or by having other options.",
+    performance_impact: "Performance impact",
+    formatter_overlap: "This rule is unnecessary if you use the formatter of Biome, which already formats the code it reports.",
+    unreleased: "This rule isn't released yet, it will be available in the next version of Biome.",
+    option_values: "Values of the options",
+    value: "Value",
+    description: "Description",
+    see_also: "See also",
+    options_schema: "JSON Schema of the options",
+    rationale: "Why is this bad?",
+    invalid: "Invalid",
+    valid: "Valid",
+    with_options: "With the options `{name}`:",
+    output: "Output",
+    inline_output: "Output: `{before}` → `{after}`",
+    full_diagnostic: "Show the full diagnostic",
+    no_diagnostic: "No diagnostic is emitted.",
+    more_diagnostics: "…and {count} more",
+    ignored_examples: "The following examples are ignored:",
+    rule_warnings: "The documentation of the following rules has warnings:",
+    rule_problem: "- {rule}: {problem}",
+    rules: "Rules",
+    rules_description: "List of available lint rules.",
+    rules_introduction: "Below the list of rules supported by Biome, divided by group. Here's a legend of the emojis:",
+    legend_icon: "The icon",
+    recommended_label: "This rule is recommended",
+    recommended_legend: "indicates that the rule is part of the recommended rules.",
+    safe_fix_label: "The rule has a safe fix",
+    safe_fix_legend: "indicates that the rule provides a code action (fix) that is **safe** to apply.",
+    unsafe_fix_label: "The rule has an unsafe fix",
+    unsafe_fix_legend: "indicates that the rule provides a code action (fix) that is **unsafe** to apply.",
+    js_label: "JavaScript and super languages rule",
+    js_legend: "indicates that the rule is applied to JavaScript and super languages files.",
+    jsx_label: "JSX rule",
+    ts_label: "TypeScript rule",
+    ts_legend: "indicates that the rule is applied to TypeScript and TSX files.",
+    json_label: "JSON rule",
+    json_legend: "indicates that the rule is applied to JSON files.",
+    css_label: "CSS rule",
+    css_legend: "indicates that the rule is applied to CSS files.",
+    nightly_label: "This rule is enabled on nightly builds",
+    nightly_legend: "indicates that the nursery rule is enabled by default on nightly builds.",
+    rule_name: "Rule name",
+    properties: "Properties",
+    example: "Example",
+    examples: "Examples",
+    unreleased_badge: "Unreleased",
+    new_badge: "New",
+    recommended_rules: "Recommended rules",
+    recommended_rules_list: "The recommended rules are:",
+    recommended_rules_by_group: "The recommended and the opt-in rules of each group are:",
+    group_column: "Group",
+    recommended_column: "Recommended",
+    opt_in_column: "Opt-in",
+    recommended_configuration: "The recommended preset is equivalent to the following configuration:",
+    analyzer_version: "These pages are generated from the version {version} of the analyzer.",
+    fixable_rules: "Fixable rules",
+    fixable_rules_description: "A page that lists the rules that provide a fix, and whether the fix is safe or unsafe",
+    fixable_rules_introduction: "The following table lists the rules that provide a fix. The safe fixes are applied by `biome check --write`, the unsafe fixes also require `--unsafe`.",
+    biome_rule_column: "Biome rule",
+    fix_column: "Fix",
+    safe_fix_value: "safe",
+    unsafe_fix_value: "unsafe",
+};
+
+const FR: Messages = Messages {
+    recommended: "Cette règle est recommandée par Biome. Une erreur de diagnostic apparaîtra lors de l'analyse de votre code.",
+    safe_fix: "Cette règle a un correctif **sûr**.",
+    unsafe_fix: "Cette règle a un correctif **non sûr**.",
+    applies_to: "S'applique à :",
+    sources: "Sources :",
+    inspired_from: "Inspirée de :",
+    same_as: "Identique à :",
+    supersedes: "Remplace :",
+    superseded_by: "Remplacée par :",
+    changes: "Modifications :",
+    diagnostic_example: "Exemple de message de diagnostic émis par cette règle :",
+    related_links: "Liens connexes",
+    disable_rule: "Désactiver une règle",
+    rule_options: "Options des règles",
+    since_version: "(depuis v{version})",
+    not_released: "(non publiée)",
+    diagnostic_category: "Catégorie du diagnostic :",
+    applied_to_files: "Cette règle s'applique aux fichiers {languages}.",
+    and: " et ",
+    js_files: "JavaScript et de ses surensembles",
+    jsx_files: "JSX et TSX",
+    ts_files: "TypeScript et TSX",
+    by_default: "Par défaut, {options}.",
+    option_default: "`{option}` vaut `{default}`",
+    nursery_group: "Cette règle fait partie du groupe [nursery](/linter/rules/#nursery).",
+    nursery_nightly_error: "Elle est activée par défaut dans les versions nightly, où ses diagnostics sont émis comme des **erreurs**.",
+    nursery_nightly_warning: "Elle est activée par défaut dans les versions nightly, où ses diagnostics sont émis comme des **avertissements**.",
+    nursery_stable: "Elle doit être activée explicitement dans la configuration des versions stables.",
+    source_kinds_explanation: "Une règle **identique** à sa source signale le même code, tandis qu'une règle **inspirée** de sa source peut se comporter différemment, par exemple en signalant plus ou moins de code ou en ayant d'autres options.",
+    performance_impact: "Impact sur les performances",
+    formatter_overlap: "Cette règle est inutile si vous utilisez le formateur de Biome, qui formate déjà le code qu'elle signale.",
+    unreleased: "Cette règle n'est pas encore publiée, elle sera disponible dans la prochaine version de Biome.",
+    option_values: "Valeurs des options",
+    value: "Valeur",
+    description: "Description",
+    see_also: "Voir aussi",
+    options_schema: "Schéma JSON des options",
+    rationale: "Pourquoi est-ce un problème ?",
+    invalid: "Incorrect",
+    valid: "Correct",
+    with_options: "Avec les options `{name}` :",
+    output: "Résultat",
+    inline_output: "Résultat : `{before}` → `{after}`",
+    full_diagnostic: "Afficher le diagnostic complet",
+    no_diagnostic: "Aucun diagnostic n'est émis.",
+    more_diagnostics: "…et {count} de plus",
+    ignored_examples: "Les exemples suivants sont ignorés :",
+    rule_warnings: "La documentation des règles suivantes a des avertissements :",
+    rule_problem: "- {rule} : {problem}",
+    rules: "Règles",
+    rules_description: "Liste des règles d'analyse disponibles.",
+    rules_introduction: "Voici la liste des règles prises en charge par Biome, par groupe. La légende des icônes :",
+    legend_icon: "L'icône",
+    recommended_label: "Cette règle est recommandée",
+    recommended_legend: "indique que la règle fait partie des règles recommandées.",
+    safe_fix_label: "La règle a un correctif sûr",
+    safe_fix_legend: "indique que la règle fournit une action de code (correctif) **sûre** à appliquer.",
+    unsafe_fix_label: "La règle a un correctif non sûr",
+    unsafe_fix_legend: "indique que la règle fournit une action de code (correctif) **non sûre** à appliquer.",
+    js_label: "Règle JavaScript et de ses surensembles",
+    js_legend: "indique que la règle s'applique aux fichiers JavaScript et de ses surensembles.",
+    jsx_label: "Règle JSX",
+    ts_label: "Règle TypeScript",
+    ts_legend: "indique que la règle s'applique aux fichiers TypeScript et TSX.",
+    json_label: "Règle JSON",
+    json_legend: "indique que la règle s'applique aux fichiers JSON.",
+    css_label: "Règle CSS",
+    css_legend: "indique que la règle s'applique aux fichiers CSS.",
+    nightly_label: "Cette règle est activée dans les versions nightly",
+    nightly_legend: "indique que la règle du groupe nursery est activée par défaut dans les versions nightly.",
+    rule_name: "Nom de la règle",
+    properties: "Propriétés",
+    example: "Exemple",
+    examples: "Exemples",
+    unreleased_badge: "Non publiée",
+    new_badge: "Nouvelle",
+    recommended_rules: "Règles recommandées",
+    recommended_rules_list: "Les règles recommandées sont :",
+    recommended_rules_by_group: "Les règles recommandées et optionnelles de chaque groupe sont :",
+    group_column: "Groupe",
+    recommended_column: "Recommandées",
+    opt_in_column: "Optionnelles",
+    recommended_configuration: "Le préréglage recommandé équivaut à la configuration suivante :",
+    analyzer_version: "Ces pages sont générées à partir de la version {version} de l'analyseur.",
+    fixable_rules: "Règles avec un correctif",
+    fixable_rules_description: "Une page qui liste les règles qui proposent un correctif, et indique si le correctif est sûr ou non",
+    fixable_rules_introduction: "Le tableau suivant liste les règles qui proposent un correctif. Les correctifs sûrs sont appliqués par `biome check --write`, les correctifs non sûrs nécessitent aussi `--unsafe`.",
+    biome_rule_column: "Règle Biome",
+    fix_column: "Correctif",
+    safe_fix_value: "sûr",
+    unsafe_fix_value: "non sûr",
+};
+
+#[cfg(test)]
+impl Messages {
+    /// Returns every string of the locale, in the order of the fields
+    pub(crate) fn strings(&self) -> Vec<&'static str> {
+        let Self {
+            recommended,
+            safe_fix,
+            unsafe_fix,
+            applies_to,
+            sources,
+            inspired_from,
+            same_as,
+            supersedes,
+            superseded_by,
+            changes,
+            diagnostic_example,
+            related_links,
+            disable_rule,
+            rule_options,
+            since_version,
+            not_released,
+            diagnostic_category,
+            applied_to_files,
+            and,
+            js_files,
+            jsx_files,
+            ts_files,
+            by_default,
+            option_default,
+            nursery_group,
+            nursery_nightly_error,
+            nursery_nightly_warning,
+            nursery_stable,
+            source_kinds_explanation,
+            performance_impact,
+            formatter_overlap,
+            unreleased,
+            option_values,
+            value,
+            description,
+            see_also,
+            options_schema,
+            rationale,
+            invalid,
+            valid,
+            with_options,
+            output,
+            inline_output,
+            full_diagnostic,
+            no_diagnostic,
+            more_diagnostics,
+            ignored_examples,
+            rule_warnings,
+            rule_problem,
+            rules,
+            rules_description,
+            rules_introduction,
+            legend_icon,
+            recommended_label,
+            recommended_legend,
+            safe_fix_label,
+            safe_fix_legend,
+            unsafe_fix_label,
+            unsafe_fix_legend,
+            js_label,
+            js_legend,
+            jsx_label,
+            ts_label,
+            ts_legend,
+            json_label,
+            json_legend,
+            css_label,
+            css_legend,
+            nightly_label,
+            nightly_legend,
+            rule_name,
+            properties,
+            example,
+            examples,
+            unreleased_badge,
+            new_badge,
+            recommended_rules,
+            recommended_rules_list,
+            recommended_rules_by_group,
+            group_column,
+            recommended_column,
+            opt_in_column,
+            recommended_configuration,
+            analyzer_version,
+            fixable_rules,
+            fixable_rules_description,
+            fixable_rules_introduction,
+            biome_rule_column,
+            fix_column,
+            safe_fix_value,
+            unsafe_fix_value,
+        } = *self;
+        vec![
+            recommended,
+            safe_fix,
+            unsafe_fix,
+            applies_to,
+            sources,
+            inspired_from,
+            same_as,
+            supersedes,
+            superseded_by,
+            changes,
+            diagnostic_example,
+            related_links,
+            disable_rule,
+            rule_options,
+            since_version,
+            not_released,
+            diagnostic_category,
+            applied_to_files,
+            and,
+            js_files,
+            jsx_files,
+            ts_files,
+            by_default,
+            option_default,
+            nursery_group,
+            nursery_nightly_error,
+            nursery_nightly_warning,
+            nursery_stable,
+            source_kinds_explanation,
+            performance_impact,
+            formatter_overlap,
+            unreleased,
+            option_values,
+            value,
+            description,
+            see_also,
+            options_schema,
+            rationale,
+            invalid,
+            valid,
+            with_options,
+            output,
+            inline_output,
+            full_diagnostic,
+            no_diagnostic,
+            more_diagnostics,
+            ignored_examples,
+            rule_warnings,
+            rule_problem,
+            rules,
+            rules_description,
+            rules_introduction,
+            legend_icon,
+            recommended_label,
+            recommended_legend,
+            safe_fix_label,
+            safe_fix_legend,
+            unsafe_fix_label,
+            unsafe_fix_legend,
+            js_label,
+            js_legend,
+            jsx_label,
+            ts_label,
+            ts_legend,
+            json_label,
+            json_legend,
+            css_label,
+            css_legend,
+            nightly_label,
+            nightly_legend,
+            rule_name,
+            properties,
+            example,
+            examples,
+            unreleased_badge,
+            new_badge,
+            recommended_rules,
+            recommended_rules_list,
+            recommended_rules_by_group,
+            group_column,
+            recommended_column,
+            opt_in_column,
+            recommended_configuration,
+            analyzer_version,
+            fixable_rules,
+            fixable_rules_description,
+            fixable_rules_introduction,
+            biome_rule_column,
+            fix_column,
+            safe_fix_value,
+            unsafe_fix_value,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_is_parsed() {
+        assert_eq!("en".parse(), Ok(Locale::En));
+        assert_eq!("fr".parse(), Ok(Locale::Fr));
+        assert_eq!(
+            "de".parse::<Locale>(),
+            Err("unknown locale `de`, expected `en` or `fr`".to_string())
+        );
+        assert_eq!(Locale::default().messages().rule_name, EN.rule_name);
+        assert_eq!(Locale::Fr.messages().rule_name, FR.rule_name);
+    }
+
+    #[test]
+    fn messages_are_not_empty() {
+        for message in EN.strings().into_iter().chain(FR.strings()) {
+            assert!(!message.trim().is_empty());
+        }
+    }
+
+    #[test]
+    fn placeholders_are_filled() {
+        assert_eq!(
+            fill(EN.by_default, &[("options", "`maxDepth` is `2`")]),
+            "By default, `maxDepth` is `2`."
+        );
+        assert_eq!(
+            fill(FR.since_version, &[("version", "1.8.0")]),
+            "(depuis v1.8.0)"
+        );
+    }
+
+    #[test]
+    fn locales_have_the_same_placeholders() {
+        let placeholders = |message: &str| {
+            message
+                .split('{')
+                .skip(1)
+                .filter_map(|rest| rest.split_once('}').map(|(name, _)| name.to_string()))
+                .collect::<Vec<_>>()
+        };
+        for (en, fr) in EN.strings().into_iter().zip(FR.strings()) {
+            assert_eq!(placeholders(en), placeholders(fr), "{en} / {fr}");
+        }
+    }
+}
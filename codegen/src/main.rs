@@ -1,5 +1,6 @@
-use codegen::lintdoc::generate_rule_docs;
+use codegen::lintdoc::{explain_rule, generate_rule_docs};
 use codegen::metadata::generate_json_metadata;
+use codegen::registry_diff::diff_registry;
 use codegen::website::generate_files;
 use codegen::{codegen_command, CodegenCommand};
 
@@ -7,14 +8,20 @@ fn main() -> anyhow::Result<()> {
     let result = codegen_command().fallback_to_usage().run();
 
     match result {
-        CodegenCommand::Rules => {
-            generate_rule_docs()?;
+        CodegenCommand::Rules(config) => {
+            generate_rule_docs(&config)?;
+        }
+        CodegenCommand::Explain(rule) => {
+            explain_rule(&rule)?;
+        }
+        CodegenCommand::RegistryDiff { update, snapshot } => {
+            diff_registry(&snapshot, update)?;
         }
         CodegenCommand::ReleaseFiles => {
             generate_files()?;
         }
-        CodegenCommand::All => {
-            generate_rule_docs()?;
+        CodegenCommand::All(config) => {
+            generate_rule_docs(&config)?;
             generate_files()?;
             generate_json_metadata()?;
         }
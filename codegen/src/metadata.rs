@@ -1,4 +1,6 @@
-use crate::project_root;
+use crate::extra_metadata::ExtraMetadata;
+use crate::lintdoc::{rule_examples, see_also_rules, RuleExample};
+use crate::{project_root, ANALYZER_VERSION};
 use biome_analyze::{
     FixKind, GroupCategory, Queryable, RegistryVisitor, Rule, RuleCategory, RuleGroup,
     RuleMetadata, RuleSource, RuleSourceKind,
@@ -14,6 +16,7 @@ use std::fs;
 #[derive(Default, Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 struct Metadata {
+    analyzer_version: &'static str,
     lints: Rules,
     syntax: Rules,
     assist: Rules,
@@ -81,8 +84,17 @@ struct JsonMetadata {
     /// The source kind of the rule
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source_kind: Option<RuleSourceKind>,
+    /// The related rules listed in the `See also` section of the documentation
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub see_also: Vec<String>,
+    /// The code blocks of the documentation, with the diagnostics of the
+    /// examples. Only the lint rules list them.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<RuleExample>,
 
     pub docs: String,
+    #[serde(skip)]
+    raw_docs: &'static str,
 }
 
 impl From<RuleMetadata> for JsonMetadata {
@@ -99,7 +111,10 @@ impl From<RuleMetadata> for JsonMetadata {
             ),
             recommended: value.recommended,
             fix_kind: value.fix_kind,
+            see_also: see_also_rules(value.docs),
+            examples: Vec::new(),
             docs: value.docs.to_string(),
+            raw_docs: value.docs,
         }
     }
 }
@@ -277,14 +292,43 @@ pub fn generate_json_metadata() -> anyhow::Result<()> {
     if metadata_file.exists() {
         fs::remove_file(&metadata_file)?;
     }
-    let mut visitor = Metadata::default();
+    let mut visitor = Metadata {
+        analyzer_version: ANALYZER_VERSION,
+        ..Metadata::default()
+    };
     biome_js_analyze::visit_registry(&mut visitor);
     biome_json_analyze::visit_registry(&mut visitor);
     biome_css_analyze::visit_registry(&mut visitor);
 
-    let content = serde_json::to_string_pretty(&visitor)?;
+    let extra_metadata = ExtraMetadata::load()?;
+    for (language, groups) in visitor.lints.languages.iter_mut() {
+        for (group, rules) in groups {
+            for (rule, metadata) in rules {
+                let frameworks = extra_metadata
+                    .rule(rule.0)
+                    .map_or(&[][..], |extra| extra.frameworks.as_slice());
+                metadata.examples = rule_examples(
+                    group.0,
+                    rule.0,
+                    metadata.raw_docs,
+                    metadata.fix_kind.is_some(),
+                    frameworks,
+                    language.0,
+                )?;
+            }
+        }
+    }
+
+    fs::write(metadata_file, metadata_module(&visitor)?)?;
 
-    let content = format!(
+    Ok(())
+}
+
+/// Returns the module of the endpoint that serves the metadata as JSON
+fn metadata_module(metadata: &Metadata) -> serde_json::Result<String> {
+    let content = serde_json::to_string_pretty(metadata)?;
+
+    Ok(format!(
         r#"export function GET() {{
 	const schema = {content};
 	// const json_file = new URL("_metadata.json", root);
@@ -296,9 +340,45 @@ pub fn generate_json_metadata() -> anyhow::Result<()> {
 	}});
 }}
 "#
-    );
+    ))
+}
 
-    fs::write(metadata_file, content)?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn analyzer_version_is_in_the_metadata() {
+        let metadata = Metadata {
+            analyzer_version: ANALYZER_VERSION,
+            ..Metadata::default()
+        };
+        let module = metadata_module(&metadata).unwrap();
+        assert!(module.contains(&format!(r#""analyzerVersion": "{ANALYZER_VERSION}""#)));
+    }
 
-    Ok(())
+    #[test]
+    fn examples_of_the_rule_are_listed() {
+        let docs = "Disallow `debugger`.\n\n\
+            ```js,expect_diagnostic\ndebugger;\n```\n\n\
+            ```js\nconst a = 1;\n```\n";
+        let mut metadata = JsonMetadata::from(RuleMetadata::new("1.0.0", "noDebugger", docs));
+        metadata.examples =
+            rule_examples("suspicious", "noDebugger", docs, true, &[], "js").unwrap();
+
+        let value = serde_json::to_value(&metadata).unwrap();
+        let examples = value["examples"].as_array().unwrap();
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0]["anchor"], "example-1");
+        assert_eq!(examples[0]["language"], "jsx");
+        assert_eq!(examples[0]["expectDiagnostic"], true);
+        assert!(examples[0]["diagnostics"]
+            .as_str()
+            .unwrap()
+            .contains("noDebugger"));
+        assert_eq!(examples[1]["anchor"], "example-2");
+        assert_eq!(examples[1]["expectDiagnostic"], false);
+        assert_eq!(examples[1]["diagnostics"], "");
+        assert!(value.get("rawDocs").is_none());
+    }
 }
@@ -0,0 +1,303 @@
+use crate::lintdoc::lint_rules;
+use anyhow::{Context, Result};
+use biome_analyze::{FixKind, RuleMetadata};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// The metadata of a lint rule that is compared across versions of Biome
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleSnapshot {
+    group: String,
+    version: String,
+    recommended: bool,
+    /// `none`, `safe` or `unsafe`
+    fix_kind: String,
+    /// The namespaced names of the source rules
+    sources: Vec<String>,
+}
+
+impl RuleSnapshot {
+    fn new(group: &str, meta: &RuleMetadata) -> Self {
+        let fix_kind = match meta.fix_kind {
+            Some(FixKind::Safe) => "safe",
+            Some(FixKind::Unsafe) => "unsafe",
+            _ => "none",
+        };
+        Self {
+            group: group.to_string(),
+            version: meta.version.to_string(),
+            recommended: meta.recommended,
+            fix_kind: fix_kind.to_string(),
+            sources: meta
+                .sources
+                .iter()
+                .map(|source| source.to_namespaced_rule_name())
+                .collect(),
+        }
+    }
+
+    /// Describes the fields that differ from the other snapshot
+    fn changes(&self, other: &Self) -> Vec<String> {
+        let mut changes = Vec::new();
+        let mut compare = |field: &str, before: String, after: String| {
+            if before != after {
+                changes.push(format!("{field} `{before}` -> `{after}`"));
+            }
+        };
+        compare("group", self.group.clone(), other.group.clone());
+        compare("version", self.version.clone(), other.version.clone());
+        compare(
+            "recommended",
+            self.recommended.to_string(),
+            other.recommended.to_string(),
+        );
+        compare("fix kind", self.fix_kind.clone(), other.fix_kind.clone());
+        // The order of the sources isn't part of the metadata
+        let sorted_sources = |snapshot: &Self| {
+            let mut sources = snapshot.sources.clone();
+            sources.sort();
+            sources.join(", ")
+        };
+        compare("sources", sorted_sources(self), sorted_sources(other));
+        changes
+    }
+}
+
+/// The rules added, removed and changed between two snapshots of the
+/// registries, keyed by the name of the rule
+#[derive(Debug, Default, Eq, PartialEq)]
+struct RegistryDiff<'a> {
+    /// The added rules, with their group
+    added: Vec<(&'a str, &'a str)>,
+    /// The removed rules, with their group
+    removed: Vec<(&'a str, &'a str)>,
+    /// The changed rules, with the description of each change
+    changed: Vec<(&'a str, Vec<String>)>,
+}
+
+fn diff<'a>(
+    previous: &'a BTreeMap<String, RuleSnapshot>,
+    current: &'a BTreeMap<String, RuleSnapshot>,
+) -> RegistryDiff<'a> {
+    let added = current
+        .iter()
+        .filter(|(rule, _)| !previous.contains_key(*rule))
+        .map(|(rule, snapshot)| (snapshot.group.as_str(), rule.as_str()))
+        .collect();
+    let removed = previous
+        .iter()
+        .filter(|(rule, _)| !current.contains_key(*rule))
+        .map(|(rule, snapshot)| (snapshot.group.as_str(), rule.as_str()))
+        .collect();
+    let changed = current
+        .iter()
+        .filter_map(|(rule, snapshot)| {
+            let changes = previous.get(rule)?.changes(snapshot);
+            (!changes.is_empty()).then_some((rule.as_str(), changes))
+        })
+        .collect();
+
+    RegistryDiff {
+        added,
+        removed,
+        changed,
+    }
+}
+
+/// Compares the lint rules of the registries with the snapshot saved at
+/// `path`, and prints the rules that were added, removed or changed.
+///
+/// The snapshot is replaced by the current registries when `update` is set.
+pub fn diff_registry(path: &Path, update: bool) -> Result<()> {
+    let current = lint_rules()
+        .into_iter()
+        .flat_map(|(group, rules)| {
+            rules
+                .into_iter()
+                .map(move |(rule, meta)| (rule.to_string(), RuleSnapshot::new(group, &meta)))
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    let previous: BTreeMap<String, RuleSnapshot> = if path.exists() {
+        let content = fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("failed to parse the snapshot {}", path.display()))?
+    } else {
+        println!(
+            "The snapshot {} doesn't exist, every rule is reported as added",
+            path.display()
+        );
+        BTreeMap::new()
+    };
+
+    let RegistryDiff {
+        added,
+        removed,
+        changed,
+    } = diff(&previous, &current);
+
+    if !added.is_empty() {
+        println!("Added rules:");
+        for (group, rule) in added {
+            println!("- {group}/{rule}");
+        }
+    }
+    if !removed.is_empty() {
+        println!("Removed rules:");
+        for (group, rule) in removed {
+            println!("- {group}/{rule}");
+        }
+    }
+    if !changed.is_empty() {
+        println!("Changed rules:");
+        for (rule, changes) in changed {
+            println!("- {rule}: {}", changes.join(", "));
+        }
+    }
+
+    if update {
+        fs::write(path, serde_json::to_string_pretty(&current)?)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_analyze::RuleSource;
+
+    fn snapshot(meta: RuleMetadata) -> RuleSnapshot {
+        RuleSnapshot::new("suspicious", &meta)
+    }
+
+    #[test]
+    fn snapshot_is_serialized_in_camel_case() {
+        let snapshot = snapshot(
+            RuleMetadata::new("1.0.0", "noDebugger", "")
+                .fix_kind(FixKind::Unsafe)
+                .sources(&[RuleSource::Eslint("no-debugger")]),
+        );
+        assert_eq!(
+            serde_json::to_value(&snapshot).unwrap(),
+            serde_json::json!({
+                "group": "suspicious",
+                "version": "1.0.0",
+                "recommended": false,
+                "fixKind": "unsafe",
+                "sources": ["no-debugger"],
+            })
+        );
+    }
+
+    #[test]
+    fn changed_fields_are_described() {
+        let before = snapshot(RuleMetadata::new("1.0.0", "noDebugger", ""));
+        assert!(before.changes(&before).is_empty());
+
+        let after = snapshot(
+            RuleMetadata::new("1.0.0", "noDebugger", "")
+                .recommended(true)
+                .fix_kind(FixKind::Safe),
+        );
+        assert_eq!(
+            before.changes(&after),
+            ["recommended `false` -> `true`", "fix kind `none` -> `safe`"]
+        );
+
+        let reordered = snapshot(RuleMetadata::new("1.0.0", "noDebugger", "").sources(&[
+            RuleSource::Eslint("no-debugger"),
+            RuleSource::Clippy("dbg_macro"),
+        ]));
+        let sources = snapshot(RuleMetadata::new("1.0.0", "noDebugger", "").sources(&[
+            RuleSource::Clippy("dbg_macro"),
+            RuleSource::Eslint("no-debugger"),
+        ]));
+        assert!(reordered.changes(&sources).is_empty());
+    }
+
+    #[test]
+    fn snapshots_are_compared() {
+        let previous = BTreeMap::from([
+            (
+                "noDebugger".to_string(),
+                snapshot(RuleMetadata::new("1.0.0", "noDebugger", "")),
+            ),
+            (
+                "noConsoleLog".to_string(),
+                snapshot(RuleMetadata::new("1.0.0", "noConsoleLog", "")),
+            ),
+            (
+                "useConst".to_string(),
+                RuleSnapshot::new("style", &RuleMetadata::new("1.0.0", "useConst", "")),
+            ),
+        ]);
+        let current = BTreeMap::from([
+            (
+                "noDebugger".to_string(),
+                snapshot(RuleMetadata::new("1.0.0", "noDebugger", "").recommended(true)),
+            ),
+            (
+                "noConsole".to_string(),
+                snapshot(RuleMetadata::new("1.6.0", "noConsole", "")),
+            ),
+            (
+                "useConst".to_string(),
+                RuleSnapshot::new("style", &RuleMetadata::new("1.0.0", "useConst", "")),
+            ),
+        ]);
+
+        assert_eq!(
+            diff(&previous, &current),
+            RegistryDiff {
+                added: vec![("suspicious", "noConsole")],
+                removed: vec![("suspicious", "noConsoleLog")],
+                changed: vec![(
+                    "noDebugger",
+                    vec!["recommended `false` -> `true`".to_string()]
+                )],
+            }
+        );
+        assert_eq!(diff(&current, &current), RegistryDiff::default());
+        assert_eq!(
+            diff(&BTreeMap::new(), &current).added,
+            [
+                ("suspicious", "noConsole"),
+                ("suspicious", "noDebugger"),
+                ("style", "useConst")
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_snapshot_is_created() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rules.json");
+
+        diff_registry(&path, false).unwrap();
+        assert!(!path.exists());
+
+        diff_registry(&path, true).unwrap();
+        let saved: BTreeMap<String, RuleSnapshot> =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(
+            saved.len(),
+            lint_rules().values().map(BTreeMap::len).sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn corrupted_snapshot_is_rejected() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("rules.json");
+        fs::write(&path, "{").unwrap();
+
+        let error = diff_registry(&path, true).unwrap_err();
+        assert!(error.to_string().contains("failed to parse the snapshot"));
+        // The corrupted snapshot isn't overwritten
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{");
+    }
+}
@@ -0,0 +1,293 @@
+use anyhow::{bail, Result};
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Renders the constructs of the rule pages that are specific to the
+/// framework of the site, such as asides and icons.
+///
+/// [Starlight] is the renderer of this site, implementing the trait allows
+/// generating the pages for another framework.
+pub trait SiteRenderer: Send + Sync {
+    /// The name of the renderer. The cached pages are keyed by it, so that
+    /// they're generated again with another renderer: each renderer needs its
+    /// own name.
+    fn name(&self) -> &'static str;
+
+    /// Writes the imports that the index of the rules needs to render the icons
+    fn write_index_imports(&self, buffer: &mut dyn io::Write) -> io::Result<()>;
+
+    /// Opens an aside, with an optional title
+    fn open_aside(
+        &self,
+        buffer: &mut dyn io::Write,
+        aside: AsideType,
+        title: Option<&str>,
+    ) -> io::Result<()>;
+
+    /// Closes the aside opened by [SiteRenderer::open_aside]
+    fn close_aside(&self, buffer: &mut dyn io::Write) -> io::Result<()>;
+
+    /// Returns the markup of an icon displayed inline with the text
+    fn icon(&self, name: &str, label: &str) -> String;
+
+    /// Returns the names of the icons rendered by [SiteRenderer::icon] in the
+    /// line, used to check that the icons exist. Fails if the markup of an
+    /// icon isn't terminated.
+    fn icon_names<'a>(&self, line: &'a str) -> Result<Vec<&'a str>>;
+}
+
+/// The renderer for [Starlight](https://starlight.astro.build/)
+pub struct Starlight;
+
+impl SiteRenderer for Starlight {
+    fn name(&self) -> &'static str {
+        "starlight"
+    }
+
+    fn write_index_imports(&self, buffer: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(
+            buffer,
+            r#"import {{ Icon }} from "@astrojs/starlight/components";"#
+        )
+    }
+
+    fn open_aside(
+        &self,
+        buffer: &mut dyn io::Write,
+        aside: AsideType,
+        title: Option<&str>,
+    ) -> io::Result<()> {
+        match title {
+            Some(title) => writeln!(buffer, ":::{}[{title}]", aside.as_str()),
+            None => writeln!(buffer, ":::{}", aside.as_str()),
+        }
+    }
+
+    fn close_aside(&self, buffer: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(buffer, ":::")
+    }
+
+    fn icon(&self, name: &str, label: &str) -> String {
+        format!(
+            "<span class='inline-icon'><Icon name=\"{name}\" label=\"{label}\" size=\"1.2rem\" /></span>"
+        )
+    }
+
+    fn icon_names<'a>(&self, line: &'a str) -> Result<Vec<&'a str>> {
+        const ICON_NAME: &str = "<Icon name=\"";
+
+        let mut names = Vec::new();
+        let mut rest = line;
+        while let Some(start) = rest.find(ICON_NAME) {
+            rest = &rest[start + ICON_NAME.len()..];
+            let Some(end) = rest.find('"') else {
+                bail!("the name of the icon `{rest}` isn't terminated");
+            };
+            let name = &rest[..end];
+            rest = &rest[end..];
+            let Some(close) = rest.find("/>") else {
+                bail!("the icon `{name}` isn't closed with `/>`");
+            };
+            names.push(name);
+            rest = &rest[close..];
+        }
+        Ok(names)
+    }
+}
+
+/// The renderer of the documentation printed to a terminal, which writes the
+/// asides and the icons as plain text
+pub struct PlainText;
+
+impl SiteRenderer for PlainText {
+    fn name(&self) -> &'static str {
+        "plain-text"
+    }
+
+    fn write_index_imports(&self, _buffer: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn open_aside(
+        &self,
+        buffer: &mut dyn io::Write,
+        _aside: AsideType,
+        title: Option<&str>,
+    ) -> io::Result<()> {
+        match title {
+            Some(title) => writeln!(buffer, "{title}:"),
+            None => Ok(()),
+        }
+    }
+
+    fn close_aside(&self, _buffer: &mut dyn io::Write) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn icon(&self, _name: &str, label: &str) -> String {
+        label.to_string()
+    }
+
+    fn icon_names<'a>(&self, _line: &'a str) -> Result<Vec<&'a str>> {
+        Ok(Vec::new())
+    }
+}
+
+/// The renderer used by the generator, [Starlight] by default
+#[derive(Clone)]
+pub struct Renderer(Arc<dyn SiteRenderer>);
+
+impl Renderer {
+    pub fn new(renderer: impl SiteRenderer + 'static) -> Self {
+        Self(Arc::new(renderer))
+    }
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new(Starlight)
+    }
+}
+
+impl fmt::Debug for Renderer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Renderer").field(&self.0.name()).finish()
+    }
+}
+
+impl std::ops::Deref for Renderer {
+    type Target = dyn SiteRenderer;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.as_ref()
+    }
+}
+
+/// The types of the asides (callouts) of the pages
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum AsideType {
+    Note,
+    #[default]
+    Tip,
+    Caution,
+    Danger,
+}
+
+impl AsideType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Note => "note",
+            Self::Tip => "tip",
+            Self::Caution => "caution",
+            Self::Danger => "danger",
+        }
+    }
+}
+
+impl FromStr for AsideType {
+    type Err = String;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        match input {
+            "note" => Ok(Self::Note),
+            "tip" => Ok(Self::Tip),
+            "caution" => Ok(Self::Caution),
+            "danger" => Ok(Self::Danger),
+            _ => Err(format!(
+                "unknown aside `{input}`, expected one of `note`, `tip`, `caution` or `danger`"
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// A renderer that records its calls, and renders the constructs with a
+    /// syntax that no site framework uses
+    #[derive(Clone, Default)]
+    pub(crate) struct RecordingRenderer {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl RecordingRenderer {
+        pub(crate) fn calls(&self) -> Vec<String> {
+            self.calls.lock().unwrap().clone()
+        }
+
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push(call);
+        }
+    }
+
+    impl SiteRenderer for RecordingRenderer {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn write_index_imports(&self, _buffer: &mut dyn io::Write) -> io::Result<()> {
+            self.record("write_index_imports".to_string());
+            Ok(())
+        }
+
+        fn open_aside(
+            &self,
+            buffer: &mut dyn io::Write,
+            aside: AsideType,
+            _title: Option<&str>,
+        ) -> io::Result<()> {
+            self.record(format!("open_aside {}", aside.as_str()));
+            writeln!(buffer, "[aside {}]", aside.as_str())
+        }
+
+        fn close_aside(&self, buffer: &mut dyn io::Write) -> io::Result<()> {
+            self.record("close_aside".to_string());
+            writeln!(buffer, "[/aside]")
+        }
+
+        fn icon(&self, name: &str, _label: &str) -> String {
+            self.record(format!("icon {name}"));
+            format!("[icon {name}]")
+        }
+
+        fn icon_names<'a>(&self, line: &'a str) -> Result<Vec<&'a str>> {
+            line.split("[icon ")
+                .skip(1)
+                .map(|rest| match rest.split_once(']') {
+                    Some((name, _)) => Ok(name),
+                    None => bail!("the icon `{rest}` isn't terminated"),
+                })
+                .collect()
+        }
+    }
+
+    #[test]
+    fn starlight_icon_names_are_read_back() {
+        let line = format!(
+            "| rule | {} {} |",
+            Starlight.icon("seti:config", "The rule has a safe fix"),
+            Starlight.icon("warning", "The rule has an unsafe fix")
+        );
+        assert_eq!(
+            Starlight.icon_names(&line).unwrap(),
+            ["seti:config", "warning"]
+        );
+    }
+
+    #[test]
+    fn unterminated_icons_are_rejected() {
+        let err = Starlight.icon_names("<Icon name=\"warning").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "the name of the icon `warning` isn't terminated"
+        );
+        let err = Starlight
+            .icon_names("<Icon name=\"warning\" label=\"Unsafe fix\"")
+            .unwrap_err();
+        assert_eq!(err.to_string(), "the icon `warning` isn't closed with `/>`");
+    }
+}
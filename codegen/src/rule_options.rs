@@ -0,0 +1,418 @@
+use biome_configuration::PartialConfiguration;
+use schemars::schema::{InstanceType, RootSchema, Schema, SchemaObject, SingleOrVec};
+use schemars::schema_for;
+
+/// The JSON Schema of the configuration, used to document the options of
+/// the rules
+pub(crate) struct RuleOptionsSchema {
+    root: RootSchema,
+}
+
+/// A value allowed by an enum-valued option
+pub(crate) struct EnumValue {
+    /// The value, serialized as JSON
+    pub(crate) value: String,
+    pub(crate) description: Option<String>,
+}
+
+impl RuleOptionsSchema {
+    pub(crate) fn new() -> Self {
+        Self {
+            root: schema_for!(PartialConfiguration),
+        }
+    }
+
+    /// Returns the schema of the options of the rule, if the rule has options
+    pub(crate) fn rule_options(&self, group: &str, rule: &str) -> Option<&SchemaObject> {
+        let rules = self.resolve(self.root.definitions.get("Rules")?)?;
+        let group = self.find_property(rules, group)?;
+        let rule = self.find_property(group, rule)?;
+        self.find_property(rule, "options")
+    }
+
+    /// Returns the name of the definition of the options of the rule in the
+    /// published schema, if the rule has options
+    pub(crate) fn rule_options_definition(&self, group: &str, rule: &str) -> Option<&str> {
+        let rules = self.resolve(self.root.definitions.get("Rules")?)?;
+        let group = self.find_property(rules, group)?;
+        let rule = self.find_property(group, rule)?;
+        let Schema::Object(options) = self.find_raw_property(rule, "options")? else {
+            return None;
+        };
+        // The reference isn't followed, unlike in `resolve`, since its name
+        // is the one of the definition
+        let reference = options.reference.as_deref().or_else(|| {
+            match options.subschemas.as_ref()?.all_of.as_deref()? {
+                [Schema::Object(member)] => member.reference.as_deref(),
+                _ => None,
+            }
+        })?;
+        let name = reference.strip_prefix("#/definitions/")?;
+        // The published schema doesn't expose the `Partial` prefix
+        Some(name.strip_prefix("Partial").unwrap_or(name))
+    }
+
+    /// Returns the properties of the options that only accept a list of
+    /// values, with these values
+    pub(crate) fn enum_properties<'a>(
+        &self,
+        options: &'a SchemaObject,
+    ) -> Vec<(&'a str, Vec<EnumValue>)> {
+        let Some(object) = &options.object else {
+            return Vec::new();
+        };
+
+        object
+            .properties
+            .iter()
+            .filter_map(|(name, property)| {
+                let values = self.enum_values(self.resolve(property)?)?;
+                Some((name.as_str(), values))
+            })
+            .collect()
+    }
+
+    /// Returns the defaults of the options that are a number, a boolean or a
+    /// string, serialized as JSON
+    pub(crate) fn scalar_defaults<'a>(&self, options: &'a SchemaObject) -> Vec<(&'a str, String)> {
+        let Some(object) = &options.object else {
+            return Vec::new();
+        };
+
+        object
+            .properties
+            .iter()
+            .filter_map(|(name, property)| {
+                let Schema::Object(property) = property else {
+                    return None;
+                };
+                let default = property.metadata.as_ref()?.default.as_ref()?;
+                let is_scalar = default.is_number() || default.is_boolean() || default.is_string();
+                is_scalar.then(|| (name.as_str(), default.to_string()))
+            })
+            .collect()
+    }
+
+    /// Follows the reference of the schema to its definition
+    fn resolve<'a>(&'a self, schema: &'a Schema) -> Option<&'a SchemaObject> {
+        let Schema::Object(object) = schema else {
+            return None;
+        };
+        match &object.reference {
+            Some(reference) => {
+                let name = reference.strip_prefix("#/definitions/")?;
+                self.resolve(self.root.definitions.get(name)?)
+            }
+            None => match object
+                .subschemas
+                .as_ref()
+                .and_then(|subschemas| subschemas.all_of.as_deref())
+            {
+                // A documented property wraps its reference in an `allOf`
+                Some([member]) if object.object.is_none() => self.resolve(member),
+                _ => Some(object),
+            },
+        }
+    }
+
+    /// Finds the property of an object, looking into the schemas it's
+    /// composed of (`anyOf`, `oneOf` and `allOf`)
+    fn find_property<'a>(
+        &'a self,
+        schema: &'a SchemaObject,
+        name: &str,
+    ) -> Option<&'a SchemaObject> {
+        self.resolve(self.find_raw_property(schema, name)?)
+    }
+
+    /// Finds the property of an object like [RuleOptionsSchema::find_property],
+    /// without following its reference
+    fn find_raw_property<'a>(&'a self, schema: &'a SchemaObject, name: &str) -> Option<&'a Schema> {
+        if let Some(property) = schema
+            .object
+            .as_ref()
+            .and_then(|object| object.properties.get(name))
+        {
+            return Some(property);
+        }
+
+        let subschemas = schema.subschemas.as_ref()?;
+        [&subschemas.any_of, &subschemas.one_of, &subschemas.all_of]
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|member| self.resolve(member))
+            .find_map(|member| self.find_raw_property(member, name))
+    }
+
+    /// Returns the values accepted by the schema, if it only accepts a list
+    /// of values. Optional schemas (`anyOf` with `null`) are unwrapped.
+    fn enum_values(&self, schema: &SchemaObject) -> Option<Vec<EnumValue>> {
+        if let Some(values) = &schema.enum_values {
+            return Some(
+                values
+                    .iter()
+                    .map(|value| EnumValue {
+                        value: value.to_string(),
+                        description: None,
+                    })
+                    .collect(),
+            );
+        }
+
+        let subschemas = schema.subschemas.as_ref()?;
+        let members = subschemas
+            .one_of
+            .as_ref()
+            .or(subschemas.any_of.as_ref())?
+            .iter()
+            .filter_map(|member| self.resolve(member))
+            .filter(|member| !is_null(member))
+            .collect::<Vec<_>>();
+        if let [member] = members[..] {
+            return self.enum_values(member);
+        }
+
+        // Each member documents one of the values
+        members
+            .into_iter()
+            .map(|member| {
+                let value = member.const_value.clone().or_else(|| {
+                    member
+                        .enum_values
+                        .as_ref()
+                        .filter(|values| values.len() == 1)
+                        .map(|values| values[0].clone())
+                })?;
+                Some(EnumValue {
+                    value: value.to_string(),
+                    description: member
+                        .metadata
+                        .as_ref()
+                        .and_then(|metadata| metadata.description.clone()),
+                })
+            })
+            .collect()
+    }
+}
+
+fn is_null(schema: &SchemaObject) -> bool {
+    matches!(
+        &schema.instance_type,
+        Some(SingleOrVec::Single(instance_type)) if **instance_type == InstanceType::Null
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A schema shaped like the one of the configuration, with a single rule
+    fn schema() -> RuleOptionsSchema {
+        let root = serde_json::json!({
+            "definitions": {
+                "Rules": {
+                    "type": "object",
+                    "properties": {
+                        "style": { "$ref": "#/definitions/Style" }
+                    }
+                },
+                "Style": {
+                    "type": "object",
+                    "properties": {
+                        "noDefaultExport": {
+                            "anyOf": [
+                                { "$ref": "#/definitions/RulePlainConfiguration" },
+                                { "type": "null" }
+                            ]
+                        },
+                        "useNamingConvention": {
+                            "description": "Enforce naming conventions.",
+                            "anyOf": [
+                                { "$ref": "#/definitions/RuleConfiguration_for_NamingConventionOptions" },
+                                { "type": "null" }
+                            ]
+                        }
+                    }
+                },
+                "RuleConfiguration_for_NamingConventionOptions": {
+                    "anyOf": [
+                        { "$ref": "#/definitions/RulePlainConfiguration" },
+                        { "$ref": "#/definitions/RuleWithOptions_for_NamingConventionOptions" }
+                    ]
+                },
+                "RulePlainConfiguration": {
+                    "type": "string",
+                    "enum": ["warn", "error", "off"]
+                },
+                "RuleWithOptions_for_NamingConventionOptions": {
+                    "type": "object",
+                    "properties": {
+                        "level": { "$ref": "#/definitions/RulePlainConfiguration" },
+                        "options": {
+                            "description": "Rule's options",
+                            "allOf": [{ "$ref": "#/definitions/PartialNamingConventionOptions" }]
+                        }
+                    }
+                },
+                "PartialNamingConventionOptions": {
+                    "type": "object",
+                    "properties": {
+                        "enumMemberCase": {
+                            "anyOf": [
+                                { "$ref": "#/definitions/EnumMemberCase" },
+                                { "type": "null" }
+                            ]
+                        },
+                        "quoteStyle": {
+                            "type": "string",
+                            "enum": ["single", "double"]
+                        },
+                        "strictCase": {
+                            "default": true,
+                            "type": ["boolean", "null"]
+                        }
+                    }
+                },
+                "EnumMemberCase": {
+                    "oneOf": [
+                        { "description": "PascalCase", "type": "string", "enum": ["PascalCase"] },
+                        { "description": "CONSTANT_CASE", "type": "string", "enum": ["CONSTANT_CASE"] }
+                    ]
+                }
+            }
+        });
+
+        RuleOptionsSchema {
+            root: serde_json::from_value(root).unwrap(),
+        }
+    }
+
+    #[test]
+    fn options_of_the_rule_are_found() {
+        let schema = schema();
+        assert!(schema.rule_options("style", "noDefaultExport").is_none());
+        assert!(schema.rule_options("style", "useUnknownRule").is_none());
+        assert!(schema
+            .rule_options("style", "useNamingConvention")
+            .is_some());
+    }
+
+    #[test]
+    fn enum_values_are_listed_with_their_description() {
+        let schema = schema();
+        let options = schema.rule_options("style", "useNamingConvention").unwrap();
+        let properties = schema
+            .enum_properties(options)
+            .into_iter()
+            .map(|(name, values)| {
+                let values = values
+                    .into_iter()
+                    .map(|value| (value.value, value.description))
+                    .collect::<Vec<_>>();
+                (name, values)
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            properties,
+            [
+                (
+                    "enumMemberCase",
+                    vec![
+                        (
+                            r#""PascalCase""#.to_string(),
+                            Some("PascalCase".to_string())
+                        ),
+                        (
+                            r#""CONSTANT_CASE""#.to_string(),
+                            Some("CONSTANT_CASE".to_string())
+                        ),
+                    ]
+                ),
+                (
+                    "quoteStyle",
+                    vec![
+                        (r#""single""#.to_string(), None),
+                        (r#""double""#.to_string(), None),
+                    ]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn options_without_a_list_of_values_are_skipped() {
+        let schema = schema();
+        assert!(schema
+            .rule_options("correctness", "noUnusedVariables")
+            .is_none());
+
+        let options = serde_json::from_value::<SchemaObject>(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "maxLength": { "type": ["integer", "null"] },
+                "style": {
+                    "oneOf": [
+                        { "description": "A known style", "type": "string", "enum": ["compact"] },
+                        { "description": "Any other style", "type": "string" }
+                    ]
+                }
+            }
+        }))
+        .unwrap();
+        // A member that isn't a single value makes the option accept any
+        // value of its type
+        assert!(schema.enum_properties(&options).is_empty());
+
+        let options = serde_json::from_value::<SchemaObject>(serde_json::json!({
+            "type": "object"
+        }))
+        .unwrap();
+        assert!(schema.enum_properties(&options).is_empty());
+    }
+
+    #[test]
+    fn definition_of_the_options_is_found() {
+        let schema = schema();
+        assert_eq!(
+            schema.rule_options_definition("style", "useNamingConvention"),
+            Some("NamingConventionOptions")
+        );
+        assert_eq!(
+            schema.rule_options_definition("style", "noDefaultExport"),
+            None
+        );
+    }
+
+    #[test]
+    fn scalar_defaults_are_listed() {
+        let schema = schema();
+        let options = schema.rule_options("style", "useNamingConvention").unwrap();
+        assert_eq!(
+            schema.scalar_defaults(options),
+            [("strictCase", "true".to_string())]
+        );
+
+        let options = serde_json::from_value::<SchemaObject>(serde_json::json!({
+            "type": "object",
+            "properties": {
+                "ignore": { "default": ["node_modules"], "type": "array" },
+                "maxDepth": { "default": 3, "type": "integer" },
+                "quoteStyle": { "default": "double", "type": "string" },
+                "settings": { "default": {}, "type": "object" },
+                "style": { "type": "string" }
+            }
+        }))
+        .unwrap();
+        // Lists and objects are too long for the note, and strings keep their
+        // quotes to tell them apart from numbers
+        assert_eq!(
+            schema.scalar_defaults(&options),
+            [
+                ("maxDepth", "3".to_string()),
+                ("quoteStyle", r#""double""#.to_string())
+            ]
+        );
+    }
+}
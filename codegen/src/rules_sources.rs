@@ -1,5 +1,7 @@
+use crate::extra_metadata::ExtraMetadata;
+use crate::renderer::{AsideType, Renderer};
 use anyhow::Result;
-use biome_analyze::RuleMetadata;
+use biome_analyze::{RuleMetadata, RuleSourceKind};
 use biome_string_case::Case;
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet};
@@ -14,9 +16,18 @@ struct SourceSet {
     inspired: bool,
 }
 
+/// The rules of a source are ordered by the name of the source rule, then by
+/// the name of the Biome rule, so that a source rule implemented by several
+/// Biome rules lists all of them. The other fields only break the ties, to
+/// keep the order consistent with the equality.
 impl Ord for SourceSet {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.source_rule_name.cmp(&other.source_rule_name)
+        self.source_rule_name
+            .cmp(&other.source_rule_name)
+            .then_with(|| self.biome_rule_name.cmp(&other.biome_rule_name))
+            .then_with(|| self.source_link.cmp(&other.source_link))
+            .then_with(|| self.biome_link.cmp(&other.biome_link))
+            .then_with(|| self.inspired.cmp(&other.inspired))
     }
 }
 
@@ -28,6 +39,8 @@ impl PartialOrd for SourceSet {
 
 pub(crate) fn generate_rule_sources(
     rules: BTreeMap<&str, BTreeMap<&'static str, RuleMetadata>>,
+    extra_metadata: &ExtraMetadata,
+    renderer: &Renderer,
 ) -> Result<Vec<u8>> {
     let mut buffer = vec![];
 
@@ -42,59 +55,60 @@ description: A page that maps lint rules from other sources to Biome
 
     let rules = rules
         .into_iter()
-        .flat_map(|(_, rule)| rule)
-        .collect::<BTreeMap<&str, RuleMetadata>>();
+        .flat_map(|(group, rules)| {
+            rules
+                .into_iter()
+                .map(move |(rule_name, metadata)| (rule_name, (group, metadata)))
+        })
+        .collect::<BTreeMap<&str, (&str, RuleMetadata)>>();
 
     let mut rules_by_source = BTreeMap::<String, BTreeSet<SourceSet>>::new();
-    let mut exclusive_biome_rules = BTreeSet::<(String, String)>::new();
+    // Rules without sources, grouped by their group, so maintainers can
+    // verify the attribution of each group at a glance
+    let mut exclusive_biome_rules = BTreeMap::<&str, BTreeSet<(String, String)>>::new();
 
-    for (rule_name, metadata) in rules {
+    for (rule_name, (group, metadata)) in rules {
         let kebab_rule_name = Case::Kebab.convert(rule_name);
         if metadata.sources.is_empty() {
-            exclusive_biome_rules.insert((
+            exclusive_biome_rules.entry(group).or_default().insert((
                 rule_name.to_string(),
                 format!("/linter/rules/{}", kebab_rule_name),
             ));
         } else {
+            // The sources are ordered by tool, then by rule
             for source in metadata.sources {
-                let set = rules_by_source.get_mut(&format!("{source}"));
-                if let Some(set) = set {
-                    set.insert(SourceSet {
+                rules_by_source
+                    .entry(format!("{source}"))
+                    .or_default()
+                    .insert(SourceSet {
                         biome_rule_name: rule_name.to_string(),
                         biome_link: format!("/linter/rules/{}", kebab_rule_name),
                         source_link: source.to_rule_url(),
                         source_rule_name: source.as_rule_name().to_string(),
-                        inspired: metadata
-                            .source_kind
-                            .map_or(false, |kind| kind.is_inspired()),
+                        inspired: matches!(
+                            extra_metadata.source_kind(&metadata, source),
+                            RuleSourceKind::Inspired
+                        ),
                     });
-                } else {
-                    let mut set = BTreeSet::new();
-                    set.insert(SourceSet {
-                        biome_rule_name: rule_name.to_string(),
-                        biome_link: format!("/linter/rules/{}", kebab_rule_name),
-                        source_link: source.to_rule_url(),
-                        source_rule_name: source.as_rule_name().to_string(),
-                        inspired: metadata.source_kind.map_or(true, |kind| kind.is_inspired()),
-                    });
-                    rules_by_source.insert(format!("{source}"), set);
-                }
             }
         }
     }
 
     writeln!(buffer, "## Biome exclusive rules",)?;
-    for (rule, link) in exclusive_biome_rules {
-        writeln!(buffer, "- [{}]({}) ", rule, link)?;
+    for (group, rules) in exclusive_biome_rules {
+        writeln!(buffer, "### `{group}`")?;
+        for (rule, link) in rules {
+            writeln!(buffer, "- [{}]({}) ", rule, link)?;
+        }
     }
 
     writeln!(buffer, "## Rules from other sources",)?;
+    renderer.open_aside(&mut buffer, AsideType::Note, None)?;
     writeln!(
         buffer,
-        r#":::note
-Some **Biome** rules might **not** have options, compared to the original rule.
-:::"#
+        "Some **Biome** rules might **not** have options, compared to the original rule."
     )?;
+    renderer.close_aside(&mut buffer)?;
 
     for (source, rules) in rules_by_source {
         writeln!(buffer, "### {source}")?;
@@ -128,3 +142,122 @@ fn push_to_table(source_set: BTreeSet<SourceSet>, buffer: &mut Vec<u8>) -> Resul
 
     Ok(footnotes)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use biome_analyze::RuleSource;
+
+    fn source_set(source_rule_name: &str, biome_rule_name: &str, inspired: bool) -> SourceSet {
+        SourceSet {
+            source_rule_name: source_rule_name.to_string(),
+            source_link: format!("https://eslint.org/docs/latest/rules/{source_rule_name}"),
+            biome_rule_name: biome_rule_name.to_string(),
+            biome_link: format!("/linter/rules/{}", Case::Kebab.convert(biome_rule_name)),
+            inspired,
+        }
+    }
+
+    #[test]
+    fn source_sets_are_ordered_consistently() {
+        let sets = [
+            source_set("no-redeclare", "noRedeclare", false),
+            source_set("no-debugger", "noDebugger", false),
+            source_set("no-redeclare", "noDuplicateDeclaration", true),
+            source_set("no-redeclare", "noRedeclare", true),
+        ];
+        for a in &sets {
+            for b in &sets {
+                assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{a:?} / {b:?}");
+            }
+        }
+
+        // The sets that only differ by a field that isn't compared first are
+        // all kept, in the same order whatever the order of insertion
+        let forward = sets.iter().collect::<BTreeSet<_>>();
+        let backward = sets.iter().rev().collect::<BTreeSet<_>>();
+        assert!(forward.iter().eq(backward.iter()));
+        assert_eq!(
+            forward
+                .iter()
+                .map(|set| (
+                    set.source_rule_name.as_str(),
+                    set.biome_rule_name.as_str(),
+                    set.inspired
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("no-debugger", "noDebugger", false),
+                ("no-redeclare", "noDuplicateDeclaration", true),
+                ("no-redeclare", "noRedeclare", false),
+                ("no-redeclare", "noRedeclare", true),
+            ]
+        );
+    }
+
+    #[test]
+    fn exclusive_rules_are_grouped() {
+        let rule = |name| RuleMetadata::new("1.0.0", name, "").language("js");
+        let rules = BTreeMap::from([
+            ("style", BTreeMap::from([("useConst", rule("useConst"))])),
+            (
+                "suspicious",
+                BTreeMap::from([
+                    ("noDebugger", rule("noDebugger")),
+                    ("noConsole", rule("noConsole")),
+                ]),
+            ),
+        ]);
+
+        let content = String::from_utf8(
+            generate_rule_sources(rules, &ExtraMetadata::default(), &Renderer::default()).unwrap(),
+        )
+        .unwrap();
+        assert!(content.contains(
+            "### `style`\n- [useConst](/linter/rules/use-const) \n### `suspicious`\n- [noConsole](/linter/rules/no-console) \n- [noDebugger](/linter/rules/no-debugger) \n"
+        ));
+
+        // A group whose rules all have a source has no heading
+        let rules = BTreeMap::from([(
+            "suspicious",
+            BTreeMap::from([(
+                "noDebugger",
+                rule("noDebugger").sources(&[RuleSource::Eslint("no-debugger")]),
+            )]),
+        )]);
+        let content = String::from_utf8(
+            generate_rule_sources(rules, &ExtraMetadata::default(), &Renderer::default()).unwrap(),
+        )
+        .unwrap();
+        assert!(
+            content.contains("## Biome exclusive rules\n## Rules from other sources\n"),
+            "{content}"
+        );
+    }
+
+    #[test]
+    fn source_kinds_are_read_per_source() {
+        let meta = RuleMetadata::new("1.0.0", "noDebugger", "Disallow the use of `debugger`.")
+            .sources(&[
+                RuleSource::Eslint("no-debugger"),
+                RuleSource::Clippy("dbg_macro"),
+            ])
+            .source_kind(RuleSourceKind::SameLogic);
+        let rules = BTreeMap::from([("suspicious", BTreeMap::from([("noDebugger", meta)]))]);
+        let extra_metadata: ExtraMetadata = serde_json::from_str(
+            r#"{ "rules": { "noDebugger": { "sourceKinds": { "no-debugger": "inspired" } } } }"#,
+        )
+        .unwrap();
+
+        let buffer = generate_rule_sources(rules, &extra_metadata, &Renderer::default()).unwrap();
+        let content = String::from_utf8(buffer).unwrap();
+        let row = |source_rule: &str| {
+            content
+                .lines()
+                .find(|line| line.starts_with(&format!("| [{source_rule}]")))
+                .unwrap_or_else(|| panic!("no row for {source_rule}:\n{content}"))
+        };
+        assert!(row("no-debugger").ends_with(" (inspired) |"));
+        assert!(!row("dbg_macro").contains("(inspired)"));
+    }
+}
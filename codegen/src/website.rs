@@ -1,4 +1,6 @@
+use crate::extra_metadata::ExtraMetadata;
 use crate::project_root;
+use anyhow::Context;
 use biome_cli::biome_command;
 use biome_configuration::PartialConfiguration;
 use biome_js_formatter::context::JsFormatOptions;
@@ -8,16 +10,21 @@ use biome_json_formatter::context::JsonFormatOptions;
 use biome_json_parser::{parse_json, JsonParserOptions};
 use biome_rowan::AstNode;
 use biome_service::VERSION;
+use biome_string_case::Case;
 use schemars::schema::{RootSchema, Schema, SchemaObject};
 use schemars::schema_for;
+use semver::Version;
 use serde_json::to_string;
+use std::collections::BTreeMap;
 use std::convert::TryInto;
+use std::fmt::Write;
 use std::fs;
 
 /// Generates the following files:
 ///
 /// - Default configuration file: `src/components/generated/DefaultConfiguration.mdx`
 /// - Changelog file: `src/content/docs/internals/changelog.md`
+/// - Removed rules file: `src/content/docs/internals/removed-rules.md`
 /// - CLI doc file: `src/content/docs/reference/cli.mdx`
 /// - Schema js file: `src/pages/schemas/<version>/schema.json.js`
 ///
@@ -27,6 +34,7 @@ use std::fs;
 pub fn generate_files() -> anyhow::Result<()> {
     generate_default_configuration()?;
     generate_changelog()?;
+    generate_removed_rules()?;
 
     if VERSION != "0.0.0" {
         generate_cli_doc()?;
@@ -89,6 +97,63 @@ tableOfContents:
     Ok(())
 }
 
+/// Generates the removed rules file: `src/content/docs/internals/removed-rules.md`
+///
+/// The removed rules aren't in the registries anymore, they're listed in
+/// `codegen/extra-metadata.json`.
+pub(crate) fn generate_removed_rules() -> anyhow::Result<()> {
+    let removed_rules_path = project_root().join("src/content/docs/internals/removed-rules.md");
+
+    let content = removed_rules_page(&ExtraMetadata::load()?)?;
+    fs::write(removed_rules_path, content)?;
+
+    Ok(())
+}
+
+/// Returns the content of the page that lists the removed rules, by version
+fn removed_rules_page(extra_metadata: &ExtraMetadata) -> anyhow::Result<String> {
+    let mut removed_rules_by_version = BTreeMap::<Version, Vec<_>>::new();
+    for removed_rule in &extra_metadata.removed_rules {
+        let version = Version::parse(&removed_rule.version).with_context(|| {
+            format!(
+                "the version `{}` of the removed rule {} isn't a valid semver version",
+                removed_rule.version, removed_rule.name
+            )
+        })?;
+        removed_rules_by_version
+            .entry(version)
+            .or_default()
+            .push(removed_rule);
+    }
+
+    let mut content = String::from(
+        r#"---
+title: Removed rules
+description: The lint rules removed from Biome, or renamed
+---
+
+The following lint rules were removed from Biome, or renamed, in the given version.
+"#,
+    );
+    // The most recent versions come first
+    for (version, removed_rules) in removed_rules_by_version.into_iter().rev() {
+        writeln!(content, "\n## v{version}\n")?;
+        for removed_rule in removed_rules {
+            match &removed_rule.replaced_by {
+                Some(replacement) => writeln!(
+                    content,
+                    "- `{}`: renamed to [{replacement}](/linter/rules/{})",
+                    removed_rule.name,
+                    Case::Kebab.convert(replacement)
+                )?,
+                None => writeln!(content, "- `{}`: removed", removed_rule.name)?,
+            }
+        }
+    }
+
+    Ok(content)
+}
+
 /// Generates the CLI doc file: `src/content/docs/reference/cli.mdx`
 pub(crate) fn generate_cli_doc() -> anyhow::Result<()> {
     let cli_doc_path = project_root().join("src/content/docs/reference/cli.mdx");
@@ -301,3 +366,26 @@ fn rename_partial_references_in_schema_slice(schemas: &mut [Schema]) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removed_rules_are_listed_by_version() {
+        let extra_metadata: ExtraMetadata = serde_json::from_str(
+            r#"{ "removedRules": [
+                { "name": "noOldRule", "version": "1.2.0" },
+                { "name": "noRenamedRule", "version": "1.10.0", "replacedBy": "noNewRule" }
+            ] }"#,
+        )
+        .unwrap();
+        let page = removed_rules_page(&extra_metadata).unwrap();
+        assert!(
+            page.ends_with(
+                "\n## v1.10.0\n\n- `noRenamedRule`: renamed to [noNewRule](/linter/rules/no-new-rule)\n\n## v1.2.0\n\n- `noOldRule`: removed\n"
+            ),
+            "{page}"
+        );
+    }
+}
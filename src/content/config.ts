@@ -30,8 +30,16 @@ const authorsSchema = z.object({
 	url: z.string().url().optional(),
 });
 
+// The properties of the rule pages, generated by the codegen
+const ruleSchema = z.object({
+	stability: z.enum(["stable", "nursery"]).optional(),
+	fixable: z.enum(["none", "safe", "unsafe"]).optional(),
+	languages: z.array(z.string()).optional(),
+	recommended: z.boolean().optional(),
+});
+
 export const collections = {
-	docs: defineCollection({ schema: docsSchema() }),
+	docs: defineCollection({ schema: docsSchema({ extend: ruleSchema }) }),
 	blog: defineCollection({
 		type: "content",
 		schema: ({ image }) => blogSchema(image),